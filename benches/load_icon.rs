@@ -0,0 +1,44 @@
+//! Cost of resolving icons by name at runtime.
+//!
+//! `load_icon_ssr` renders icons by name to HTML the way an SSR server does
+//! for dynamic icons, including a name that falls back to the placeholder.
+//! `archive_read` is the baseline it replaced: reading an icon's markup from
+//! the `lucide-svg-rs` archive on every call, as `load_icon` did before the
+//! build script embedded each icon's nodes.
+//!
+//! Run with `cargo bench --bench load_icon --features ssr`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use leptos::prelude::*;
+use leptos_lucide_rs::*;
+
+fn load_icon_ssr(c: &mut Criterion) {
+    let owner = Owner::new();
+    let mut group = c.benchmark_group("load_icon_ssr");
+    for name in ["house", "circle-alert", "no-such-icon"] {
+        group.bench_function(name, |b| {
+            b.iter(|| owner.with(|| load_icon(black_box(name)).to_html()))
+        });
+    }
+    group.finish();
+}
+
+/// Children of an icon's `<svg>`, read from the archive
+fn read_archive_inner_markup(name: &str) -> Option<String> {
+    let client = lucide_svg_rs::LucideClient::new(lucide_svg_rs::ICONS_TAR).ok()?;
+    let svg = client.get_icon_content(&format!("{name}.svg")).ok()?;
+    let start = svg.find('>')? + 1;
+    let end = svg.rfind("</svg>")?;
+    Some(svg[start..end].to_string())
+}
+
+fn archive_read(c: &mut Criterion) {
+    let mut group = c.benchmark_group("archive_read");
+    group.bench_function("house", |b| {
+        b.iter(|| read_archive_inner_markup(black_box("house")))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, load_icon_ssr, archive_read);
+criterion_main!(benches);
@@ -1,12 +1,25 @@
 use convert_case::{Case, Casing};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
+#[path = "build/svg.rs"]
+mod svg;
+
+#[path = "src/defaults.rs"]
+#[allow(dead_code)]
+mod defaults;
+
+use svg::SvgNode;
+
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build/svg.rs");
+    println!("cargo:rerun-if-changed=data/aliases.txt");
+    println!("cargo:rerun-if-env-changed=LEPTOS_LUCIDE_PRECISION");
+    println!("cargo:rerun-if-env-changed=LEPTOS_LUCIDE_REPORT");
     println!("cargo::rustc-check-cfg=cfg(leptos_lucide_generated)");
 
     let out_dir = std::env::var("OUT_DIR").unwrap();
@@ -15,36 +28,184 @@ fn main() {
     // Get list of available icon names
     let icon_names = get_available_icon_names();
 
-    // Generate icon components
-    let generated_code = generate_icon_components(&icon_names);
+    // Map renamed icons to their current names
+    let aliases = get_icon_aliases(&icon_names);
+
+    // Parse and optimize the child elements of every icon
+    let precision = get_precision();
+    let (icon_nodes, savings) = get_icon_nodes(
+        &icon_names,
+        &Path::new(&out_dir).join("lucide-icons"),
+        precision,
+    );
+
+    // Generate icon components, one module per first letter
+    let shards = generate_icon_components(&icon_names, &icon_nodes, &aliases);
+    let keys: Vec<char> = shards.iter().map(|(key, _)| *key).collect();
+    let shard_dir = Path::new(&out_dir).join("icons");
+    fs::create_dir_all(&shard_dir).expect("Failed to create icon module directory");
+    for (key, code) in &shards {
+        write_generated(&shard_dir.join(format!("{}.rs", shard_ident(*key))), code);
+    }
+
+    let mut generated_code = generate_icon_modules(&keys);
+    generated_code.extend(generate_icon_name_enum(&icon_names, &keys, &aliases));
+    let version = get_lucide_svg_rs_version();
+    generated_code.extend(generate_icon_constants(&icon_names, &version));
 
     // Write to file
-    fs::write(&dest_path, generated_code.to_string()).expect("Failed to write generated icons");
+    write_generated(&dest_path, &generated_code);
 
     println!("cargo:rustc-cfg=leptos_lucide_generated");
     println!("Generated {} icon components", icon_names.len());
+
+    let savings_path = Path::new(&out_dir).join("svg-savings.txt");
+    println!("{}", write_savings_report(&savings, precision, &savings_path));
+
+    // Per-icon markup weight, for tracking bundle budgets over time
+    let report_path = Path::new(&out_dir).join("leptos-lucide-report.json");
+    let summary = write_bundle_report(
+        &icon_names,
+        &icon_nodes,
+        &savings,
+        &version,
+        precision,
+        &report_path,
+    );
+    if std::env::var("LEPTOS_LUCIDE_REPORT").is_ok_and(|value| !matches!(value.as_str(), "" | "0"))
+    {
+        println!("cargo:warning={summary}");
+        println!("cargo:warning=Per-icon report: {}", report_path.display());
+    }
+}
+
+/// Format generated code with `prettyplease` and write it to `path`, one
+/// item per block, so go-to-definition and reviews land on readable source
+fn write_generated(path: &Path, code: &TokenStream) {
+    let file: syn::File = syn::parse2(code.clone()).expect("Generated code doesn't parse");
+    let items: Vec<String> = file
+        .items
+        .into_iter()
+        .map(|item| {
+            prettyplease::unparse(&syn::File {
+                shebang: None,
+                attrs: Vec::new(),
+                items: vec![item],
+            })
+        })
+        .collect();
+    let source = format!(
+        "// @generated by build.rs; do not edit\n\n{}",
+        tidy_view_macros(&items.join("\n"))
+    );
+    fs::write(path, source).expect("Failed to write generated code");
+}
+
+/// Lay out the `view!` bodies `prettyplease` prints as spaced-out tokens with
+/// one element per line, e.g. `< path d = "..." / >` becomes `<path d="..."/>`
+fn tidy_view_macros(source: &str) -> String {
+    const OPEN: &str = "view! {";
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+
+    while let Some(start) = rest.find(OPEN) {
+        let line_start = rest[..start].rfind('\n').map_or(0, |i| i + 1);
+        let indent = rest[line_start..].len() - rest[line_start..].trim_start().len();
+        out.push_str(&rest[..start + OPEN.len()]);
+        rest = &rest[start + OPEN.len()..];
+
+        // Split the body into tokens up to the closing brace, keeping string
+        // literals whole
+        let mut tokens = Vec::new();
+        let mut chars = rest.char_indices().peekable();
+        let end = loop {
+            let Some((i, c)) = chars.next() else {
+                break rest.len();
+            };
+            match c {
+                '}' => break i,
+                c if c.is_whitespace() => {}
+                '"' => {
+                    let mut escaped = false;
+                    let mut j = rest.len();
+                    for (k, c) in chars.by_ref() {
+                        if c == '"' && !escaped {
+                            j = k + 1;
+                            break;
+                        }
+                        escaped = c == '\\' && !escaped;
+                    }
+                    tokens.push(&rest[i..j]);
+                }
+                _ => {
+                    let mut j = rest.len();
+                    while let Some(&(k, c)) = chars.peek() {
+                        if c.is_whitespace() || c == '"' || c == '}' {
+                            j = k;
+                            break;
+                        }
+                        chars.next();
+                    }
+                    tokens.push(&rest[i..j]);
+                }
+            }
+        };
+
+        let mut elements = Vec::new();
+        let mut element = String::new();
+        let mut previous = "";
+        for token in tokens {
+            match token {
+                "/" => {}
+                ">" if previous == "/" => element.push_str("/>"),
+                ">" => element.push('>'),
+                "<" | "=" | "-" => element.push_str(token),
+                _ if element.is_empty() || element.ends_with(['<', '=', '-']) => {
+                    element.push_str(token)
+                }
+                _ => {
+                    element.push(' ');
+                    element.push_str(token);
+                }
+            }
+            if token == ">" {
+                elements.push(std::mem::take(&mut element));
+            }
+            previous = token;
+        }
+
+        for element in elements {
+            out.push_str(&format!("\n{:width$}{element}", "", width = indent + 4));
+        }
+        out.push_str(&format!("\n{:indent$}", ""));
+        rest = &rest[end..];
+    }
+
+    out.push_str(rest);
+    out
 }
 
 /// Get a deduplicated list of common Lucide icon names
 fn get_available_icon_names() -> Vec<String> {
     let mut unique_names = HashSet::new();
 
-    let client = lucide_svg_rs::LucideClient::default();
     let mut names: Vec<String> = Vec::new();
-    if let Ok((_, n)) = client.download_all_icons(lucide_svg_rs::ICONS_TAR) {
+    if let Ok(n) = lucide_svg_rs::LucideClient::new(lucide_svg_rs::ICONS_TAR)
+        .and_then(|client| client.list_icons())
+    {
         names.extend(n)
     } else {
         names.extend(
             vec![
                 // Navigation & UI
-                "home",
+                "house",
                 "user",
                 "settings",
                 "menu",
                 "search",
-                "filter",
-                "more-horizontal",
-                "more-vertical",
+                "funnel",
+                "ellipsis",
+                "ellipsis-vertical",
                 // Arrows & Directions
                 "arrow-left",
                 "arrow-right",
@@ -62,7 +223,7 @@ fn get_available_icon_names() -> Vec<String> {
                 "minus",
                 "x",
                 "check",
-                "edit",
+                "pencil",
                 "trash",
                 "copy",
                 "download",
@@ -91,13 +252,14 @@ fn get_available_icon_names() -> Vec<String> {
                 "thumbs-up",
                 "star",
                 // Status & Feedback
-                "alert-circle",
-                "alert-triangle",
+                "circle-alert",
+                "triangle-alert",
                 "info",
-                "help-circle",
-                "check-circle",
-                "x-circle",
+                "circle-question-mark",
+                "circle-check-big",
+                "circle-x",
                 "loader",
+                "loader-circle",
                 "refresh-cw",
                 // Tools & Objects
                 "calendar",
@@ -107,7 +269,7 @@ fn get_available_icon_names() -> Vec<String> {
                 "credit-card",
                 "key",
                 "lock",
-                "unlock",
+                "lock-open",
                 "shield",
                 "eye",
                 "eye-off",
@@ -141,22 +303,21 @@ fn get_available_icon_names() -> Vec<String> {
                 "building",
                 "trending-up",
                 "trending-down",
-                "pie-chart",
-                "bar-chart",
+                "chart-pie",
+                "chart-no-axes-column-increasing",
                 "activity",
                 // Development & Code
                 "code",
                 "terminal",
                 "git-branch",
-                "git-commit",
+                "git-commit-horizontal",
                 "git-merge",
                 "database",
                 "server",
                 "wifi",
                 // Layout & Design
-                "layout",
-                "sidebar",
-                "panels",
+                "panels-top-left",
+                "panel-left",
                 "maximize",
                 "minimize",
                 "move",
@@ -179,136 +340,751 @@ fn get_available_icon_names() -> Vec<String> {
     result
 }
 
-fn generate_icon_components(icon_names: &[String]) -> TokenStream {
+/// Rendered in place of icons whose SVG source cannot be read
+const PLACEHOLDER_PATH: &str = "M12 2L2 7l10 5 10-5-10-5zM2 17l10 5 10-5M2 12l10 5 10-5";
+
+fn placeholder_nodes() -> Vec<SvgNode> {
+    vec![SvgNode {
+        tag: "path".to_string(),
+        attrs: vec![("d".to_string(), PLACEHOLDER_PATH.to_string())],
+    }]
+}
+
+/// Coordinate precision from `LEPTOS_LUCIDE_PRECISION`, in decimal places
+fn get_precision() -> usize {
+    match std::env::var("LEPTOS_LUCIDE_PRECISION") {
+        Ok(value) => match value.trim().parse::<usize>() {
+            Ok(precision) if precision <= 8 => precision,
+            _ => {
+                println!("cargo:warning=Ignoring LEPTOS_LUCIDE_PRECISION={value}: expected 0 to 8");
+                svg::DEFAULT_PRECISION
+            }
+        },
+        Err(_) => svg::DEFAULT_PRECISION,
+    }
+}
+
+/// Byte size of an icon's SVG before and after optimization
+struct IconSavings {
+    name: String,
+    before: usize,
+    after: usize,
+}
+
+/// Extract the icon archive into `icons_dir`, then parse and optimize each
+/// icon's children.
+///
+/// The returned list is in the same order as `icon_names`. Icons that are
+/// missing or fail to parse get the placeholder shape and no savings entry.
+fn get_icon_nodes(
+    icon_names: &[String],
+    icons_dir: &Path,
+    precision: usize,
+) -> (Vec<Vec<SvgNode>>, Vec<IconSavings>) {
+    let extracted = lucide_svg_rs::LucideClient::new(lucide_svg_rs::ICONS_TAR)
+        .and_then(|client| client.download_all_icons(icons_dir))
+        .is_ok();
+
+    let mut savings = Vec::new();
+    let nodes = icon_names
+        .iter()
+        .map(|name| {
+            let source = extracted
+                .then(|| fs::read_to_string(icons_dir.join(format!("{name}.svg"))).ok())
+                .flatten();
+            let Some(source) = source else {
+                return placeholder_nodes();
+            };
+            match svg::parse_svg(&source) {
+                Ok(document) => {
+                    let nodes = svg::optimize(&document, precision);
+                    savings.push(IconSavings {
+                        name: name.clone(),
+                        before: source.len(),
+                        after: svg::serialize(&document.root_attrs, &nodes).len(),
+                    });
+                    nodes
+                }
+                Err(err) => {
+                    println!("cargo:warning=Failed to parse {name}.svg: {err}");
+                    placeholder_nodes()
+                }
+            }
+        })
+        .collect();
+
+    (nodes, savings)
+}
+
+/// Write the per-icon savings to `path` and return the total line
+fn write_savings_report(savings: &[IconSavings], precision: usize, path: &Path) -> String {
+    let before: usize = savings.iter().map(|icon| icon.before).sum();
+    let after: usize = savings.iter().map(|icon| icon.after).sum();
+    let percent = |before: usize, after: usize| {
+        if before == 0 {
+            0.0
+        } else {
+            (before - after.min(before)) as f64 * 100.0 / before as f64
+        }
+    };
+
+    let mut report =
+        format!("# SVG optimization, precision {precision}\n# icon before after saved%\n");
+    for icon in savings {
+        report.push_str(&format!(
+            "{} {} {} {:.1}\n",
+            icon.name,
+            icon.before,
+            icon.after,
+            percent(icon.before, icon.after)
+        ));
+    }
+    let total = format!(
+        "Optimized {} icon SVGs: {before} -> {after} bytes ({:.1}% saved)",
+        savings.len(),
+        percent(before, after)
+    );
+    report.push_str(&format!("# {total}\n"));
+
+    fs::write(path, report).expect("Failed to write SVG savings report");
+    total
+}
+
+/// Quote and escape a string for JSON
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Write `leptos-lucide-report.json` with every icon's markup size and the
+/// totals for the generated set, and return a one-line summary.
+///
+/// `markup_bytes` counts the child elements an icon embeds; the root `<svg>`
+/// is shared by every icon. `source_bytes` is the size of the original SVG
+/// file, or `null` for icons rendered with the placeholder.
+fn write_bundle_report(
+    icon_names: &[String],
+    icon_nodes: &[Vec<SvgNode>],
+    savings: &[IconSavings],
+    version: &str,
+    precision: usize,
+    path: &Path,
+) -> String {
+    let source_bytes: HashMap<&str, usize> = savings
+        .iter()
+        .map(|icon| (icon.name.as_str(), icon.before))
+        .collect();
+
+    let mut entries = Vec::with_capacity(icon_names.len());
+    let mut total_markup = 0;
+    let mut largest: Option<(&str, usize)> = None;
+    for (name, nodes) in icon_names.iter().zip(icon_nodes) {
+        let markup_bytes = svg::serialize_children(nodes).len();
+        total_markup += markup_bytes;
+        if largest.is_none_or(|(_, bytes)| markup_bytes > bytes) {
+            largest = Some((name, markup_bytes));
+        }
+        let source = source_bytes
+            .get(name.as_str())
+            .map_or("null".to_string(), |bytes| bytes.to_string());
+        entries.push(format!(
+            "    {{\"name\": {}, \"component\": {}, \"category\": {}, \"elements\": {}, \"markup_bytes\": {markup_bytes}, \"source_bytes\": {source}}}",
+            json_string(name),
+            json_string(&to_component_name(name)),
+            json_string(icon_category(name)),
+            nodes.len(),
+        ));
+    }
+
+    let count = icon_names.len();
+    let average = total_markup.checked_div(count).unwrap_or(0);
+    let total_source: usize = savings.iter().map(|icon| icon.before).sum();
+    let report = format!(
+        "{{\n  \"lucide_svg_rs_version\": {},\n  \"precision\": {precision},\n  \"totals\": {{\"icons\": {count}, \"markup_bytes\": {total_markup}, \"average_markup_bytes\": {average}, \"source_bytes\": {total_source}}},\n  \"icons\": [\n{}\n  ]\n}}\n",
+        json_string(version),
+        entries.join(",\n"),
+    );
+    fs::write(path, report).expect("Failed to write bundle report");
+
+    let mut summary =
+        format!("{count} icons, {total_markup} bytes of markup (average {average} bytes per icon)");
+    if let Some((name, bytes)) = largest {
+        summary.push_str(&format!(", largest {name} at {bytes} bytes"));
+    }
+    summary
+}
+
+/// Version of the `lucide-svg-rs` package whose archive the icons come from.
+///
+/// The archive carries no version metadata of its own, so the Lucide release
+/// behind it can't be named; the package version pins it down instead. It is
+/// read from the manifest next to the archive.
+fn get_lucide_svg_rs_version() -> String {
+    let archive = Path::new(lucide_svg_rs::ICONS_TAR);
+    if !archive.exists() {
+        return "fallback".to_string();
+    }
+
+    archive
+        .parent()
+        .map(|dir| dir.join("Cargo.toml"))
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|manifest| manifest.parse::<toml::Table>().ok())
+        .and_then(|manifest| {
+            manifest
+                .get("package")?
+                .get("version")?
+                .as_str()
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Read `data/aliases.txt` and keep the aliases that can be emitted.
+///
+/// An alias is skipped when its replacement is missing from the icon set or
+/// when the old name is still a real icon.
+fn get_icon_aliases(icon_names: &[String]) -> Vec<(String, String)> {
+    let data = fs::read_to_string("data/aliases.txt").expect("Failed to read icon aliases");
+    let known: HashSet<&str> = icon_names.iter().map(String::as_str).collect();
+
+    let mut aliases: Vec<(String, String)> = data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once(char::is_whitespace))
+        .map(|(old, new)| (old.trim(), new.trim()))
+        .filter(|(old, new)| known.contains(new) && !known.contains(old))
+        .map(|(old, new)| (old.to_string(), new.to_string()))
+        .collect();
+    aliases.sort();
+    aliases.dedup_by(|a, b| a.0 == b.0);
+    aliases
+}
+
+/// First character of an icon name, which picks the module it's generated in
+fn shard_key(icon_name: &str) -> char {
+    icon_name.chars().next().unwrap_or('_')
+}
+
+/// Module holding the icons whose names start with `key`, e.g. `icons_a`
+fn shard_ident(key: char) -> Ident {
+    Ident::new(&format!("icons_{key}"), Span::call_site())
+}
+
+/// Generate one module per first letter of the icon names.
+///
+/// Each module holds its icons' components, the deprecated components of
+/// renamed icons, and the `load`/`nodes` lookups the crate-level `load_icon`
+/// and `IconName::nodes` dispatch to. Splitting the set keeps every function
+/// and codegen unit small, so an incremental build only redoes the modules it
+/// has to.
+fn generate_icon_components(
+    icon_names: &[String],
+    icon_nodes: &[Vec<SvgNode>],
+    aliases: &[(String, String)],
+) -> Vec<(char, TokenStream)> {
+    let mut keys: Vec<char> = icon_names
+        .iter()
+        .chain(aliases.iter().map(|(old, _)| old))
+        .map(|name| shard_key(name))
+        .collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .map(|key| {
+            let icons: Vec<(&String, &Vec<SvgNode>)> = icon_names
+                .iter()
+                .zip(icon_nodes)
+                .filter(|(name, _)| shard_key(name) == key)
+                .collect();
+            let renamed: Vec<&(String, String)> = aliases
+                .iter()
+                .filter(|(old, _)| shard_key(old) == key)
+                .collect();
+            (key, generate_icon_shard(&icons, &renamed, aliases))
+        })
+        .collect()
+}
+
+/// Generate one letter's module; `all_aliases` is only read for the docs
+fn generate_icon_shard(
+    icons: &[(&String, &Vec<SvgNode>)],
+    aliases: &[&(String, String)],
+    all_aliases: &[(String, String)],
+) -> TokenStream {
     let mut components = Vec::new();
 
-    for name in icon_names {
+    for (name, nodes) in icons {
         let component_name = to_component_name(name);
         let component_ident = Ident::new(&component_name, Span::call_site());
+        let children = nodes.iter().map(svg_node_view);
+        let doc = icon_doc(name, nodes, all_aliases);
+        let shape_fill = nodes
+            .iter()
+            .any(svg::takes_shape_fill)
+            .then(|| quote! { let shape_fill = props.shape_fill(); });
+
+        // Filled icons whose fills would cover open strokes draw them first
+        let fill_order = svg::fill_order(nodes);
+        let children = if fill_order.iter().zip(nodes.iter()).any(|(a, b)| !std::ptr::eq(*a, b)) {
+            let filled_children = fill_order.into_iter().map(svg_node_view);
+            quote! {
+                if shape_fill.is_some() {
+                    leptos::either::Either::Left(view! { #(#filled_children)* })
+                } else {
+                    leptos::either::Either::Right(view! { #(#children)* })
+                }
+            }
+        } else {
+            quote! { view! { #(#children)* } }
+        };
 
         let component = quote! {
+            #(#[doc = #doc])*
             #[inline(always)]
             #[allow(non_snake_case)]
-            pub fn #component_ident() -> impl leptos::IntoView {
-                use leptos::*;
-
-                let svg_content = move || {
-                    let client = lucide_svg_rs::LucideClient::default();
-                    match client.get_icon_content(&format!("{}.svg", #name)) {
-                        Ok(svg_content) => {
-                            // Extract content between <svg> tags
-                            if let (Some(start), Some(end)) = (svg_content.find('>'), svg_content.rfind("</svg>")) {
-                                if start < end {
-                                    return svg_content[start + 1..end].to_string();
-                                }
-                            }
-                            // Fallback if parsing fails
-                            r#"<path d="M12 2L2 7l10 5 10-5-10-5zM2 17l10 5 10-5M2 12l10 5 10-5"></path>"#.to_string()
-                        }
-                        Err(_) => {
-                            // Fallback icon
-                            r#"<path d="M12 2L2 7l10 5 10-5-10-5zM2 17l10 5 10-5M2 12l10 5 10-5"></path>"#.to_string()
-                        }
-                    }
-                };
-
-                view! {
-                    <svg
-                        class="lucide-icon"
-                        xmlns="http://www.w3.org/2000/svg"
-                        width="24"
-                        height="24"
-                        viewBox="0 0 24 24"
-                        fill="none"
-                        stroke="currentColor"
-                        stroke-width="2"
-                        stroke-linecap="round"
-                        stroke-linejoin="round"
-                        data-lucide=#name
-                        inner_html=svg_content
-                    />
-                }
+            pub fn #component_ident(props: LucideProps) -> impl leptos::IntoView {
+                use leptos::prelude::*;
+                #shape_fill
+
+                icon_svg(#name, props, #children)
             }
         };
 
         components.push(component);
     }
 
-    // Generate the load_icon function
-    let load_icon_match_arms = icon_names.iter().map(|name| {
-        let component_name = to_component_name(name);
-        let component_ident = Ident::new(&component_name, Span::call_site());
+    for (old, new) in aliases {
+        let alias_ident = Ident::new(&to_component_name(old), Span::call_site());
+        let target_name = to_component_name(new);
+        let target_ident = Ident::new(&target_name, Span::call_site());
+        let note = format!("use {target_name}");
+        let doc = format!("Renamed upstream: `{old}` is now `{new}`");
+
+        components.push(quote! {
+            #[doc = #doc]
+            #[deprecated(note = #note)]
+            #[inline(always)]
+            #[allow(non_snake_case)]
+            pub fn #alias_ident(props: LucideProps) -> impl leptos::IntoView {
+                #target_ident(props)
+            }
+        });
+    }
+
+    let load_icon_match_arms = icons
+        .iter()
+        .map(|(name, _)| (*name, *name))
+        .chain(aliases.iter().map(|(old, new)| (old, new)))
+        .map(|(name, target)| {
+            let component_name = to_component_name(target);
+            let component_ident = Ident::new(&component_name, Span::call_site());
+            quote! {
+                #name => || #component_ident(LucideProps::default()).into_any(),
+            }
+        });
+
+    let variants: Vec<Ident> = icons
+        .iter()
+        .map(|(name, _)| Ident::new(&to_component_name(name), Span::call_site()))
+        .collect();
+    let nodes = icons.iter().map(|(_, nodes)| {
+        let nodes = nodes.iter().map(svg_node_data);
+        quote! { &[#(#nodes),*] }
+    });
+
+    quote! {
+        use super::*;
+
+        // Generated icon components
+        #(#components)*
+
+        /// Constructor for the icon named `name`, if it's in this module
+        pub(super) fn load(name: &str) -> Option<fn() -> leptos::prelude::AnyView> {
+            use leptos::prelude::*;
+
+            // Each arm yields a function pointer so the icon views are built in
+            // their own stack frames rather than all inlined into `load_icon`
+            Some(match name {
+                #(#load_icon_match_arms)*
+                _ => return None,
+            })
+        }
+
+        /// Child elements of an icon in this module
+        pub(super) const fn nodes(icon: IconName) -> &'static [IconNode] {
+            match icon {
+                #(IconName::#variants => #nodes,)*
+                _ => &[],
+            }
+        }
+    }
+}
+
+/// Width and height of the preview in icon docs, twice the default size
+const PREVIEW_SIZE: &str = "48";
+
+/// Stroke of the preview in icon docs
+///
+/// Mid-gray, since `currentColor` can't reach into an image, and gray reads on
+/// both light and dark themes.
+const PREVIEW_STROKE: &str = "#888";
+
+/// Doc comment lines for an icon component: its Lucide name, category, former
+/// names and a preview image
+///
+/// The preview is a base64 data URI, which rustdoc and IDE hovers render
+/// inline. The icon archive carries no Lucide tags or categories, so the
+/// category is the keyword match from [`icon_category`] and the docs link to
+/// lucide.dev for the tags.
+fn icon_doc(name: &str, nodes: &[SvgNode], aliases: &[(String, String)]) -> Vec<String> {
+    use base64::Engine;
+
+    let root_attrs: Vec<(String, String)> = [
+        ("xmlns", "http://www.w3.org/2000/svg"),
+        ("width", PREVIEW_SIZE),
+        ("height", PREVIEW_SIZE),
+        ("viewBox", "0 0 24 24"),
+        ("fill", defaults::DEFAULT_FILL),
+        ("stroke", PREVIEW_STROKE),
+        ("stroke-width", defaults::DEFAULT_STROKE_WIDTH),
+        ("stroke-linecap", "round"),
+        ("stroke-linejoin", "round"),
+    ]
+    .iter()
+    .map(|(attr, value)| (attr.to_string(), value.to_string()))
+    .collect();
+    let preview =
+        base64::engine::general_purpose::STANDARD.encode(svg::serialize(&root_attrs, nodes));
+
+    let mut doc = vec![
+        format!(" Lucide icon `{name}`"),
+        String::new(),
+        format!(" ![{name}](data:image/svg+xml;base64,{preview})"),
+        String::new(),
+        format!(" Picker category: {}", icon_category(name)),
+        String::new(),
+        format!(" <https://lucide.dev/icons/{name}>"),
+    ];
+    let former: Vec<String> = aliases
+        .iter()
+        .filter(|(_, new)| new == name)
+        .map(|(old, _)| format!("`{old}`"))
+        .collect();
+    if !former.is_empty() {
+        doc.push(String::new());
+        doc.push(format!(" Formerly named {}.", former.join(", ")));
+    }
+    doc
+}
+
+/// Declare the icon modules, re-export their components and dispatch
+/// `load_icon` to the module matching the name's first letter
+fn generate_icon_modules(keys: &[char]) -> TokenStream {
+    let modules = keys.iter().map(|&key| {
+        let module = shard_ident(key);
+        let path = format!("/icons/{module}.rs");
         quote! {
-            #name => #component_ident().into_any(),
+            mod #module {
+                include!(concat!(env!("OUT_DIR"), #path));
+            }
+            pub use #module::*;
         }
     });
+    let bytes = keys.iter().map(|&key| key as u8);
+    let module_idents = keys.iter().map(|&key| shard_ident(key));
+
+    quote! {
+        #(#modules)*
 
-    let load_icon_function = quote! {
         /// Load an icon by name at runtime
         pub fn load_icon(name: &str) -> leptos::prelude::AnyView {
-            match name {
-                #(#load_icon_match_arms)*
-                _ => {
-                    // Fallback for unknown icons
-                    let client = lucide_svg_rs::LucideClient::default();
-                    match client.get_icon_content(&format!("{}.svg", name)) {
-                        Ok(svg_content) => {
-                            if let (Some(start), Some(end)) = (svg_content.find('>'), svg_content.rfind("</svg>")) {
-                                if start < end {
-                                    let inner = svg_content[start + 1..end].to_string();
-                                    return view! {
-                                        <svg
-                                            class="lucide-icon"
-                                            xmlns="http://www.w3.org/2000/svg"
-                                            width="24"
-                                            height="24"
-                                            viewBox="0 0 24 24"
-                                            fill="none"
-                                            stroke="currentColor"
-                                            stroke-width="2"
-                                            stroke-linecap="round"
-                                            stroke-linejoin="round"
-                                            data-lucide=name
-                                            inner_html=move || inner.clone()
-                                        />
-                                    }.into_any();
-                                }
-                            }
-                        }
-                        Err(_) => {}
-                    }
+            let render = match name.as_bytes().first() {
+                #(Some(#bytes) => #module_idents::load(name),)*
+                _ => None,
+            };
+            match render {
+                Some(render) => render(),
+                // Unknown names get the placeholder shape. Nothing is read
+                // at runtime, so the server and a hydrating client agree.
+                None => icon_svg(
+                    name.to_string(),
+                    LucideProps::default(),
+                    view! { <path d=#PLACEHOLDER_PATH/> },
+                )
+                .into_any(),
+            }
+        }
+    }
+}
+
+/// Keywords used to sort icons into picker categories.
+///
+/// The bundled icon archive only ships SVG files, so categories are derived
+/// from the dash-separated segments of each icon name. The first category
+/// with a matching keyword wins; anything left over lands in `Other`.
+const CATEGORY_KEYWORDS: &[(&str, &[&str])] = &[
+    (
+        "Status",
+        &[
+            "alert", "info", "help", "check", "ban", "badge", "bell", "loader", "siren",
+        ],
+    ),
+    (
+        "Arrows",
+        &[
+            "arrow", "arrows", "chevron", "chevrons", "move", "corner", "undo", "redo", "refresh",
+            "rotate", "repeat", "shuffle", "iteration", "between",
+        ],
+    ),
+    (
+        "Files",
+        &[
+            "file", "files", "folder", "folders", "archive", "clipboard", "paperclip", "save",
+            "book", "notebook", "sticky",
+        ],
+    ),
+    (
+        "Communication",
+        &[
+            "mail", "message", "messages", "phone", "send", "inbox", "at", "contact", "voicemail",
+            "megaphone", "rss",
+        ],
+    ),
+    (
+        "Media",
+        &[
+            "play", "pause", "music", "video", "camera", "image", "images", "film", "mic",
+            "volume", "speaker", "headphones", "radio", "tv", "podcast", "disc", "skip", "rewind",
+            "gallery", "clapperboard",
+        ],
+    ),
+    (
+        "Text",
+        &[
+            "text", "type", "bold", "italic", "underline", "strikethrough", "heading", "align",
+            "list", "pilcrow", "quote", "baseline", "case", "spell", "subscript", "superscript",
+            "indent", "outdent", "wrap", "letter",
+        ],
+    ),
+    (
+        "Charts",
+        &["chart", "trending", "activity", "gauge", "percent", "sigma", "tally"],
+    ),
+    (
+        "Development",
+        &[
+            "code", "terminal", "git", "github", "gitlab", "bug", "braces", "brackets", "database",
+            "server", "cpu", "binary", "variable", "function", "regex", "webhook", "bot",
+            "container", "package",
+        ],
+    ),
+    (
+        "Weather",
+        &[
+            "sun", "moon", "cloud", "cloudy", "rain", "snow", "wind", "thermometer", "umbrella",
+            "sunrise", "sunset", "tornado", "rainbow", "droplet", "droplets", "haze", "snowflake",
+        ],
+    ),
+    (
+        "Devices",
+        &[
+            "monitor", "laptop", "smartphone", "tablet", "keyboard", "mouse", "printer", "watch",
+            "battery", "plug", "usb", "hard", "wifi", "bluetooth", "router", "cast", "signal",
+        ],
+    ),
+    (
+        "Navigation",
+        &[
+            "map", "pin", "navigation", "compass", "locate", "globe", "earth", "route",
+            "signpost", "milestone", "car", "bus", "plane", "train", "ship", "bike", "house",
+        ],
+    ),
+    (
+        "Security",
+        &["lock", "unlock", "key", "shield", "fingerprint", "scan", "eye"],
+    ),
+    ("People", &["user", "users", "person", "baby", "hand", "smile"]),
+    (
+        "Commerce",
+        &[
+            "shopping", "cart", "credit", "wallet", "banknote", "coins", "dollar", "euro",
+            "receipt", "store", "tag", "tags", "gift", "ticket", "briefcase", "building",
+        ],
+    ),
+    (
+        "Layout",
+        &[
+            "layout", "panel", "panels", "sidebar", "columns", "rows", "grid", "table", "layers",
+            "app", "window", "maximize", "minimize", "fullscreen", "frame", "group", "ungroup",
+        ],
+    ),
+    (
+        "Shapes",
+        &[
+            "circle", "square", "squares", "triangle", "hexagon", "octagon", "pentagon",
+            "diamond", "shapes", "cone", "cylinder", "pyramid", "torus", "rectangle", "star",
+            "heart",
+        ],
+    ),
+];
+
+fn icon_category(icon_name: &str) -> &'static str {
+    CATEGORY_KEYWORDS
+        .iter()
+        .find(|(_, keywords)| icon_name.split('-').any(|part| keywords.contains(&part)))
+        .map(|(category, _)| *category)
+        .unwrap_or("Other")
+}
+
+fn generate_icon_name_enum(
+    icon_names: &[String],
+    keys: &[char],
+    aliases: &[(String, String)],
+) -> TokenStream {
+    let variants: Vec<Ident> = icon_names
+        .iter()
+        .map(|name| Ident::new(&to_component_name(name), Span::call_site()))
+        .collect();
+    let component_names: Vec<String> = icon_names.iter().map(|n| to_component_name(n)).collect();
+    let categories = icon_names
+        .iter()
+        .map(|name| Ident::new(icon_category(name), Span::call_site()));
+    let bytes = keys.iter().map(|&key| key as u8);
+    let modules = keys.iter().map(|&key| shard_ident(key));
+    let former_names = icon_names.iter().map(|name| {
+        let former = aliases
+            .iter()
+            .filter(|(_, new)| new == name)
+            .map(|(old, _)| old);
+        quote! { &[#(#former),*] }
+    });
+    let alias_names = aliases.iter().map(|(old, _)| old);
+    let alias_targets = aliases
+        .iter()
+        .map(|(_, new)| Ident::new(&to_component_name(new), Span::call_site()));
+
+    quote! {
+        /// Identifies one of the icons generated for this build
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum IconName {
+            #(#variants,)*
+        }
+
+        impl IconName {
+            /// Every generated icon, sorted by Lucide name
+            pub const ALL: &'static [IconName] = &[#(IconName::#variants,)*];
+
+            /// The kebab-case Lucide name, e.g. `"arrow-left"`
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    #(IconName::#variants => #icon_names,)*
+                }
+            }
+
+            /// The generated component name, e.g. `"ArrowLeft"`
+            pub const fn component_name(self) -> &'static str {
+                match self {
+                    #(IconName::#variants => #component_names,)*
+                }
+            }
+
+            /// Look up an icon by its kebab-case Lucide name
+            ///
+            /// Names of renamed icons resolve to their current replacement.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    #(#icon_names => Some(IconName::#variants),)*
+                    #(#alias_names => Some(IconName::#alias_targets),)*
+                    _ => None,
+                }
+            }
+
+            /// Names Lucide used for this icon before renaming it, e.g.
+            /// `["home"]` for `House`
+            pub const fn former_names(self) -> &'static [&'static str] {
+                match self {
+                    #(IconName::#variants => #former_names,)*
+                }
+            }
 
-                    // Ultimate fallback
-                    view! {
-                        <svg
-                            class="lucide-icon"
-                            xmlns="http://www.w3.org/2000/svg"
-                            width="24"
-                            height="24"
-                            viewBox="0 0 24 24"
-                            fill="none"
-                            stroke="currentColor"
-                            stroke-width="2"
-                            stroke-linecap="round"
-                            stroke-linejoin="round"
-                            data-lucide=name
-                        >
-                            <path d="M12 2L2 7l10 5 10-5-10-5zM2 17l10 5 10-5M2 12l10 5 10-5"/>
-                        </svg>
-                    }.into_any()
+            /// The icon's child elements, as embedded at build time
+            pub const fn nodes(self) -> &'static [IconNode] {
+                match self.as_str().as_bytes()[0] {
+                    #(#bytes => #modules::nodes(self),)*
+                    _ => &[],
+                }
+            }
+
+            /// The category this icon is grouped under
+            pub const fn category(self) -> IconCategory {
+                match self {
+                    #(IconName::#variants => IconCategory::#categories,)*
                 }
             }
         }
-    };
+
+        impl std::fmt::Display for IconName {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    }
+}
+
+fn generate_icon_constants(icon_names: &[String], version: &str) -> TokenStream {
+    let count = icon_names.len();
+    let component_names = icon_names.iter().map(|name| to_component_name(name));
 
     quote! {
-        // Generated icon components
-        #(#components)*
+        /// Number of icon components generated for this build
+        pub const ICON_COUNT: usize = #count;
+
+        /// `(kebab-case, PascalCase)` names of every generated icon
+        pub const ALL_ICON_NAMES: &[(&str, &str)] = &[#((#icon_names, #component_names),)*];
 
-        #load_icon_function
+        /// Version of the icon set this build was generated from
+        ///
+        /// The bundled archive doesn't record a Lucide release, so this is the
+        /// version of the `lucide-svg-rs` package the icons were taken from.
+        pub const LUCIDE_VERSION: &str = #version;
     }
 }
 
+/// Emit a child element as a typed `view!` node, e.g. `<path d="..."/>`
+fn svg_node_view(node: &SvgNode) -> TokenStream {
+    let tag: TokenStream = node.tag.parse().expect("Invalid SVG tag");
+    let attrs = node.attrs.iter().map(|(name, value)| {
+        let name: TokenStream = name.parse().expect("Invalid SVG attribute name");
+        quote! { #name=#value }
+    });
+    let shape_fill = svg::takes_shape_fill(node).then(|| quote! { fill=shape_fill.clone() });
+    quote! { <#tag #(#attrs)* #shape_fill /> }
+}
+
+/// Emit a child element as `IconNode` data for the string renderer
+fn svg_node_data(node: &SvgNode) -> TokenStream {
+    let tag = &node.tag;
+    let attrs = node
+        .attrs
+        .iter()
+        .map(|(name, value)| quote! { (#name, #value) });
+    let closed = svg::is_closed(node);
+    quote! { IconNode { tag: #tag, attrs: &[#(#attrs),*], closed: #closed } }
+}
+
 fn to_component_name(icon_name: &str) -> String {
     // Convert kebab-case to PascalCase
     icon_name.to_case(Case::Pascal)
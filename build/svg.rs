@@ -0,0 +1,422 @@
+//! SVG parsing and optimization for the build script.
+//!
+//! Shared with `tests/svg_optimize.rs` through `#[path]`, so it only depends on
+//! `std` and `roxmltree`.
+
+/// SVG elements allowed as children of an icon's root `<svg>`
+pub const SVG_CHILD_TAGS: &[&str] = &[
+    "path", "circle", "rect", "line", "polyline", "ellipse", "polygon",
+];
+
+/// Decimal places kept when rounding coordinates, unless overridden with
+/// `LEPTOS_LUCIDE_PRECISION`
+pub const DEFAULT_PRECISION: usize = 3;
+
+/// Presentation attributes children inherit from the root `<svg>`
+const INHERITED_ATTRS: &[&str] = &[
+    "fill",
+    "stroke",
+    "stroke-width",
+    "stroke-linecap",
+    "stroke-linejoin",
+];
+
+/// Attributes holding numbers or path data that can be rounded
+const NUMERIC_ATTRS: &[&str] = &[
+    "d", "points", "cx", "cy", "r", "rx", "ry", "x", "y", "x1", "y1", "x2", "y2", "width", "height",
+];
+
+/// A child element of an icon's root `<svg>`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SvgNode {
+    pub tag: String,
+    pub attrs: Vec<(String, String)>,
+}
+
+/// An icon's root attributes and child elements
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SvgDocument {
+    pub root_attrs: Vec<(String, String)>,
+    pub children: Vec<SvgNode>,
+}
+
+/// Parse an icon's SVG source
+pub fn parse_svg(source: &str) -> Result<SvgDocument, String> {
+    let document = roxmltree::Document::parse(source).map_err(|err| err.to_string())?;
+    let root = document.root_element();
+
+    let children = root
+        .children()
+        .filter(|node| node.is_element())
+        .map(|node| {
+            let tag = node.tag_name().name();
+            if !SVG_CHILD_TAGS.contains(&tag) {
+                return Err(format!("unsupported element <{tag}>"));
+            }
+            Ok(SvgNode {
+                tag: tag.to_string(),
+                attrs: node
+                    .attributes()
+                    .map(|attr| (attr.name().to_string(), attr.value().to_string()))
+                    .collect(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(SvgDocument {
+        root_attrs: root
+            .attributes()
+            .map(|attr| (attr.name().to_string(), attr.value().to_string()))
+            .collect(),
+        children,
+    })
+}
+
+/// Optimize an icon's children without changing how they render.
+///
+/// Collapses whitespace, drops presentation attributes that repeat the root
+/// `<svg>`'s value, rounds numbers to `precision` decimal places and strips
+/// separators path data doesn't need.
+pub fn optimize(document: &SvgDocument, precision: usize) -> Vec<SvgNode> {
+    let inherited = |name: &str, value: &str| {
+        INHERITED_ATTRS.contains(&name)
+            && document
+                .root_attrs
+                .iter()
+                .any(|(root_name, root_value)| root_name == name && root_value.trim() == value)
+    };
+
+    document
+        .children
+        .iter()
+        .map(|node| SvgNode {
+            tag: node.tag.clone(),
+            attrs: node
+                .attrs
+                .iter()
+                .filter_map(|(name, value)| {
+                    let mut value = collapse_whitespace(value);
+                    if inherited(name, &value) {
+                        return None;
+                    }
+                    if NUMERIC_ATTRS.contains(&name.as_str()) {
+                        value = round_numbers(&value, precision);
+                    }
+                    if name == "d" {
+                        value = compact_path(&value);
+                    }
+                    Some((name.clone(), value))
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Trim and replace every run of whitespace with a single space
+pub fn collapse_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Round every decimal number in `value` to `precision` places, dropping
+/// trailing zeros and leading `0`s before the decimal point.
+///
+/// Integers are copied untouched, so arc flags written without separators
+/// (e.g. `011`) keep their meaning.
+pub fn round_numbers(value: &str, precision: usize) -> String {
+    let bytes = value.as_bytes();
+    let mut out = String::with_capacity(value.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        if bytes[i] == b'-' || bytes[i] == b'+' {
+            i += 1;
+        }
+        let int_start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let has_int = i > int_start;
+        let mut has_fraction = false;
+        if i < bytes.len() && bytes[i] == b'.' {
+            let fraction_start = i + 1;
+            let mut j = fraction_start;
+            while j < bytes.len() && bytes[j].is_ascii_digit() {
+                j += 1;
+            }
+            if j > fraction_start {
+                has_fraction = true;
+                i = j;
+            }
+        }
+        let has_exponent = i < bytes.len() && matches!(bytes[i], b'e' | b'E');
+
+        if !has_int && !has_fraction {
+            // Not a number; copy one character
+            let c = value[start..].chars().next().unwrap();
+            out.push(c);
+            i = start + c.len_utf8();
+            continue;
+        }
+
+        let number = &value[start..i];
+        if !has_fraction || has_exponent {
+            out.push_str(number);
+            continue;
+        }
+
+        let rounded = format_number(number.parse::<f64>().unwrap(), precision);
+        // A sign or leading `.` can be the only separator, as in `.76-.001`
+        // or `.88.001`; keep the numbers apart if rounding drops it
+        if rounded.starts_with(|c: char| c.is_ascii_digit())
+            && out.ends_with(|c: char| c.is_ascii_digit() || c == '.')
+        {
+            out.push(' ');
+        }
+        // `1.0004.5` is two numbers; keep them apart once the first loses
+        // its decimal point
+        let next_is_dot = bytes.get(i) == Some(&b'.');
+        out.push_str(&rounded);
+        if next_is_dot && !rounded.contains('.') {
+            out.push(' ');
+        }
+    }
+
+    out
+}
+
+/// Shortest decimal form of `number` rounded to `precision` places
+pub fn format_number(number: f64, precision: usize) -> String {
+    let mut text = format!("{number:.precision$}");
+    if text.contains('.') {
+        text.truncate(text.trim_end_matches('0').trim_end_matches('.').len());
+    }
+    if text == "-0" {
+        text = "0".to_string();
+    }
+    if let Some(rest) = text.strip_prefix("0.") {
+        text = format!(".{rest}");
+    } else if let Some(rest) = text.strip_prefix("-0.") {
+        text = format!("-.{rest}");
+    }
+    text
+}
+
+/// Drop spaces path data doesn't need: around command letters and before a
+/// minus sign
+pub fn compact_path(d: &str) -> String {
+    let chars: Vec<char> = d.chars().collect();
+    let mut out = String::with_capacity(d.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == ' ' {
+            let prev = out.chars().last();
+            let next = chars.get(i + 1).copied();
+            let redundant = prev.is_none_or(|prev| prev.is_ascii_alphabetic() || prev == ',')
+                || next.is_none_or(|next| next.is_ascii_alphabetic() || next == '-' || next == ',');
+            if redundant {
+                continue;
+            }
+        }
+        out.push(c);
+    }
+
+    out
+}
+
+/// Whether a child element encloses an area that `filled` icons paint
+///
+/// Basic shapes always do; paths only when every subpath is closed, so open
+/// strokes such as arrows stay unfilled.
+pub fn is_closed(node: &SvgNode) -> bool {
+    match node.tag.as_str() {
+        "circle" | "ellipse" | "rect" | "polygon" => true,
+        "path" => node
+            .attrs
+            .iter()
+            .find(|(name, _)| name == "d")
+            .is_some_and(|(_, d)| path_is_closed(d)),
+        _ => false,
+    }
+}
+
+/// Whether a `filled` icon paints this element: it's closed and has no fill
+/// of its own
+pub fn takes_shape_fill(node: &SvgNode) -> bool {
+    is_closed(node) && !node.attrs.iter().any(|(name, _)| name == "fill")
+}
+
+/// Order a `filled` icon draws `nodes` in: the elements it paints first,
+/// keeping their relative order, so fills never cover open strokes
+///
+/// Unfilled icons keep Lucide's order. Every element is stroked in the same
+/// color, so the outline renders the same either way.
+pub fn fill_order(nodes: &[SvgNode]) -> Vec<&SvgNode> {
+    let mut order: Vec<&SvgNode> = nodes.iter().collect();
+    order.sort_by_key(|node| !takes_shape_fill(node));
+    order
+}
+
+/// Whether every subpath of path data is closed, either with `z` or by
+/// ending where it started
+///
+/// Returns `false` for path data that can't be parsed.
+pub fn path_is_closed(d: &str) -> bool {
+    let chars: Vec<char> = d.chars().collect();
+    let mut i = 0;
+    let mut command = None;
+    let (mut x, mut y) = (0.0, 0.0);
+    let mut start = (0.0, 0.0);
+    // Whether the current subpath has segments not yet closed with `z`
+    let mut open = false;
+    let mut drawn = false;
+
+    let ends_at_start = |x: f64, y: f64, start: (f64, f64)| {
+        (x - start.0).abs() < 1e-3 && (y - start.1).abs() < 1e-3
+    };
+
+    loop {
+        while i < chars.len() && (chars[i].is_whitespace() || chars[i] == ',') {
+            i += 1;
+        }
+        let Some(&c) = chars.get(i) else {
+            break;
+        };
+
+        if c.is_ascii_alphabetic() {
+            i += 1;
+            match c {
+                'Z' | 'z' => {
+                    (x, y) = start;
+                    open = false;
+                    command = None;
+                    continue;
+                }
+                'M' | 'm' => {
+                    if open && !ends_at_start(x, y, start) {
+                        return false;
+                    }
+                    open = false;
+                }
+                _ => {}
+            }
+            command = Some(c);
+            continue;
+        }
+
+        let Some(command_char) = command else {
+            return false;
+        };
+        let count = match command_char.to_ascii_lowercase() {
+            'm' | 'l' | 't' => 2,
+            'h' | 'v' => 1,
+            's' | 'q' => 4,
+            'c' => 6,
+            'a' => 7,
+            _ => return false,
+        };
+        let mut args = [0.0; 7];
+        for (index, arg) in args.iter_mut().take(count).enumerate() {
+            // Arc flags are single digits and may be written without separators
+            let flag = command_char.eq_ignore_ascii_case(&'a') && (index == 3 || index == 4);
+            match read_number(&chars, &mut i, flag) {
+                Some(number) => *arg = number,
+                None => return false,
+            }
+        }
+
+        let relative = command_char.is_ascii_lowercase();
+        let (dx, dy) = if relative { (x, y) } else { (0.0, 0.0) };
+        match command_char.to_ascii_lowercase() {
+            'h' => x = args[0] + dx,
+            'v' => y = args[0] + dy,
+            other => {
+                let end = match other {
+                    'm' | 'l' | 't' => 0,
+                    's' | 'q' => 2,
+                    'c' => 4,
+                    _ => 5,
+                };
+                (x, y) = (args[end] + dx, args[end + 1] + dy);
+            }
+        }
+
+        if command_char.eq_ignore_ascii_case(&'m') {
+            start = (x, y);
+            // Further pairs after a moveto are linetos
+            command = Some(if relative { 'l' } else { 'L' });
+        } else {
+            open = true;
+            drawn = true;
+        }
+    }
+
+    drawn && (!open || ends_at_start(x, y, start))
+}
+
+/// Read one number of path data at `i`, skipping separators before it
+fn read_number(chars: &[char], i: &mut usize, flag: bool) -> Option<f64> {
+    while *i < chars.len() && (chars[*i].is_whitespace() || chars[*i] == ',') {
+        *i += 1;
+    }
+    let start = *i;
+    if flag {
+        return match chars.get(*i) {
+            Some('0') | Some('1') => {
+                *i += 1;
+                Some(if chars[start] == '1' { 1.0 } else { 0.0 })
+            }
+            _ => None,
+        };
+    }
+
+    if matches!(chars.get(*i), Some('-') | Some('+')) {
+        *i += 1;
+    }
+    let mut seen_dot = false;
+    while let Some(&c) = chars.get(*i) {
+        match c {
+            '0'..='9' => {}
+            '.' if !seen_dot => seen_dot = true,
+            _ => break,
+        }
+        *i += 1;
+    }
+    if matches!(chars.get(*i), Some('e') | Some('E')) {
+        *i += 1;
+        if matches!(chars.get(*i), Some('-') | Some('+')) {
+            *i += 1;
+        }
+        while chars.get(*i).is_some_and(char::is_ascii_digit) {
+            *i += 1;
+        }
+    }
+
+    chars[start..*i].iter().collect::<String>().parse().ok()
+}
+
+/// Serialize a standalone `<svg>` document, e.g. to measure its size
+pub fn serialize(root_attrs: &[(String, String)], children: &[SvgNode]) -> String {
+    let mut svg = String::from("<svg");
+    for (name, value) in root_attrs {
+        svg.push_str(&format!(" {name}=\"{value}\""));
+    }
+    svg.push('>');
+    svg.push_str(&serialize_children(children));
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Serialize child elements without a root, i.e. the markup one icon adds
+pub fn serialize_children(children: &[SvgNode]) -> String {
+    let mut markup = String::new();
+    for node in children {
+        markup.push('<');
+        markup.push_str(&node.tag);
+        for (name, value) in &node.attrs {
+            markup.push_str(&format!(" {name}=\"{value}\""));
+        }
+        markup.push_str("/>");
+    }
+    markup
+}
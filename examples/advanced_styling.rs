@@ -4,6 +4,8 @@
 #[component]
 fn App() -> impl IntoView {
     view! {
+        // Animation keyframes; with `leptos_meta`, use `<LucideStyles/>` instead
+        <style>{LUCIDE_CSS}</style>
         <div class="app">
             <h1>"Leptos Lucide Icons - Advanced Styling"</h1>
 
@@ -11,19 +13,19 @@ fn App() -> impl IntoView {
                 <h2>"Size Variations"</h2>
                 <div class="size-demo">
                     <div class="size-item icon-small">
-                        <Home />
+                        <House />
                         <span>"16px"</span>
                     </div>
                     <div class="size-item icon-medium">
-                        <Home />
+                        <House />
                         <span>"24px"</span>
                     </div>
                     <div class="size-item icon-large">
-                        <Home />
+                        <House />
                         <span>"32px"</span>
                     </div>
                     <div class="size-item icon-xl">
-                        <Home />
+                        <House />
                         <span>"48px"</span>
                     </div>
                 </div>
@@ -55,23 +57,21 @@ fn App() -> impl IntoView {
                 <h2>"Animations"</h2>
                 <div class="animation-demo">
                     <div class="animate-item">
-                        <div class="icon-spin">
-                            <Loader />
-                        </div>
+                        <Loader animation=IconAnimation::Spin />
                         <span>"Spin"</span>
                     </div>
                     <div class="animate-item">
-                        <div class="icon-bounce">
-                            <ArrowUp />
-                        </div>
+                        <ArrowUp animation=IconAnimation::Bounce />
                         <span>"Bounce"</span>
                     </div>
                     <div class="animate-item">
-                        <div class="icon-pulse">
-                            <Heart />
-                        </div>
+                        <Heart animation=IconAnimation::Pulse />
                         <span>"Pulse"</span>
                     </div>
+                    <div class="animate-item">
+                        <Bell animation=IconAnimation::Ping />
+                        <span>"Ping"</span>
+                    </div>
                 </div>
             </div>
 
@@ -97,20 +97,21 @@ fn App() -> impl IntoView {
                 <h2>"Icon Combinations"</h2>
                 <div class="combo-demo">
                     <div class="combo-item">
-                        <div class="icon-stack">
-                            <div class="icon-bg">
-                                <Square />
-                            </div>
-                            <div class="icon-fg">
-                                <Heart />
-                            </div>
-                        </div>
+                        <IconStack size="48">
+                            <IconLayer icon=IconName::Square color="#e5e7eb" />
+                            <IconLayer icon=IconName::Heart scale=0.5 color="#ef4444" />
+                        </IconStack>
                         <span>"Stacked"</span>
                     </div>
 
+                    <div class="combo-item">
+                        <IconBadge icon=IconName::Bell count=5 size="48" />
+                        <span>"Badge"</span>
+                    </div>
+
                     <div class="combo-item">
                         <div class="icon-row">
-                            <Home />
+                            <House />
                             <ChevronRight />
                             <User />
                         </div>
@@ -9,8 +9,8 @@ fn App() -> impl IntoView {
 
             <div class="icon-grid">
                 <div class="icon-item">
-                    <Home/>
-                    <span>"Home"</span>
+                    <House/>
+                    <span>"House"</span>
                 </div>
 
                 <div class="icon-item">
@@ -39,7 +39,7 @@ fn App() -> impl IntoView {
 
                 <div class="example">
                     <h3>"Basic Usage"</h3>
-                    <Home/>
+                    <House/>
                 </div>
 
                 <div class="example">
@@ -55,9 +55,10 @@ fn App() -> impl IntoView {
                 </div>
             </div>
 
-            // <div class="stats">
-            //     <p>"Total available icons: " {ICON_COUNT}</p>
-            // </div>
+            <div class="stats">
+                <p>"Total available icons: " {ICON_COUNT}</p>
+                <p>"Icon set version: " {LUCIDE_VERSION}</p>
+            </div>
         </div>
     }
 }
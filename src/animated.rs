@@ -0,0 +1,320 @@
+//! Icons that animate when swapped for another icon
+
+use crate::render::icon_children;
+use crate::{IconConfig, IconName, SvgAttributes};
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// Default length of an [`AnimatedIcon`] transition, in milliseconds
+pub const DEFAULT_TRANSITION_MS: u32 = 300;
+
+/// Attributes browsers can transition as CSS geometry properties; the
+/// `.lucide-morph` rule in [`LUCIDE_CSS`](crate::LUCIDE_CSS) lists the same
+const MORPH_ATTRIBUTES: &[&str] = &[
+    "d", "cx", "cy", "r", "rx", "ry", "x", "y", "width", "height",
+];
+
+/// How [`AnimatedIcon`] moves from one icon to the next
+///
+/// The keyframes come from [`LUCIDE_CSS`](crate::LUCIDE_CSS).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IconTransition {
+    /// Fade the old icon out while the new one fades in
+    #[default]
+    Crossfade,
+    /// Turn the old icon away and the new one into place
+    Rotate,
+    /// Shrink the old icon away and grow the new one
+    Scale,
+}
+
+impl IconTransition {
+    /// Every transition
+    pub const ALL: &'static [IconTransition] = &[
+        IconTransition::Crossfade,
+        IconTransition::Rotate,
+        IconTransition::Scale,
+    ];
+
+    /// Class added to the [`AnimatedIcon`] wrapper, e.g. `"lucide-crossfade"`
+    pub const fn class(self) -> &'static str {
+        match self {
+            IconTransition::Crossfade => "lucide-crossfade",
+            IconTransition::Rotate => "lucide-rotate",
+            IconTransition::Scale => "lucide-scale",
+        }
+    }
+}
+
+impl IconName {
+    /// Whether this icon's shapes can be interpolated into `other`'s
+    ///
+    /// True when both have the same elements with the same attributes, and
+    /// every value that differs is one CSS can transition: path data using
+    /// the same commands with the same number of arguments, or the position
+    /// and size of a circle, ellipse or rect. The points of lines, polylines
+    /// and polygons can't be transitioned, so those have to match exactly.
+    ///
+    /// ```rust
+    /// use leptos_lucide_rs::IconName;
+    ///
+    /// assert!(IconName::ChevronLeft.can_morph_into(IconName::ChevronRight));
+    /// assert!(!IconName::Menu.can_morph_into(IconName::X));
+    /// ```
+    pub fn can_morph_into(self, other: IconName) -> bool {
+        let (from, to) = (self.nodes(), other.nodes());
+        from.len() == to.len()
+            && from.iter().zip(to).all(|(from, to)| {
+                from.tag == to.tag
+                    && from.attrs.len() == to.attrs.len()
+                    && from
+                        .attrs
+                        .iter()
+                        .zip(to.attrs)
+                        .all(|(&(name, from), &(to_name, to))| {
+                            name == to_name && (from == to || can_transition(name, from, to))
+                        })
+            })
+    }
+}
+
+/// Whether CSS can transition the attribute `name` from one value to another
+fn can_transition(name: &str, from: &str, to: &str) -> bool {
+    match name {
+        "d" => path_shape(from) == path_shape(to),
+        _ => MORPH_ATTRIBUTES.contains(&name),
+    }
+}
+
+/// Command letters of path data, each with its number of arguments
+fn path_shape(d: &str) -> Vec<(char, usize)> {
+    let mut shape: Vec<(char, usize)> = Vec::new();
+    let mut in_number = false;
+    let mut seen_dot = false;
+
+    for c in d.chars() {
+        let starts_number = match c {
+            c if c.is_ascii_alphabetic() => {
+                shape.push((c, 0));
+                in_number = false;
+                continue;
+            }
+            '0'..='9' => !in_number,
+            '.' => {
+                let starts = !in_number || seen_dot;
+                seen_dot = true;
+                starts
+            }
+            '-' | '+' => true,
+            _ => {
+                in_number = false;
+                continue;
+            }
+        };
+        if starts_number {
+            if let Some((_, count)) = shape.last_mut() {
+                *count += 1;
+            }
+            in_number = true;
+            seen_dot = c == '.';
+        }
+    }
+
+    shape
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LayerState {
+    /// Shown without playing a transition, e.g. on first render
+    Idle,
+    Entering,
+    Leaving,
+}
+
+/// One icon in the stack; the previous icon stays as a leaving layer until
+/// its transition ends
+///
+/// Layers are added from an effect, which disposes arena signals it created on
+/// its next run, so they hold reference-counted signals instead.
+#[derive(Clone)]
+struct Layer {
+    id: usize,
+    icon: ArcRwSignal<IconName>,
+    state: ArcRwSignal<LayerState>,
+}
+
+/// Icon that transitions to the new icon whenever `icon` changes, e.g. to
+/// toggle between `Menu` and `X`
+///
+/// The old and new icons are stacked in one grid cell while the transition
+/// plays. With `morph`, icons whose shapes are compatible (see
+/// [`IconName::can_morph_into`]) are interpolated in place instead, using CSS
+/// transitions on the shapes' geometry; browsers that can't animate path data
+/// switch straight to the new shape. Other pairs, such as icons whose lines
+/// move, play `transition` as usual. Needs [`LUCIDE_CSS`](crate::LUCIDE_CSS),
+/// e.g. from `LucideStyles`; users who prefer reduced motion see the icons
+/// swap instantly.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn MenuToggle() -> impl IntoView {
+///     let (open, set_open) = signal(false);
+///     let icon = Signal::derive(move || if open.get() { IconName::X } else { IconName::Menu });
+///
+///     view! {
+///         <button on:click=move |_| set_open.update(|open| *open = !*open)>
+///             <AnimatedIcon icon=icon transition=IconTransition::Rotate/>
+///         </button>
+///     }
+/// }
+/// ```
+#[component]
+pub fn AnimatedIcon(
+    /// Icon to show
+    #[prop(into)]
+    icon: Signal<IconName>,
+
+    /// Transition played when the icon changes
+    #[prop(optional)]
+    transition: IconTransition,
+
+    /// Interpolate between icons with compatible shapes instead
+    #[prop(optional)]
+    morph: bool,
+
+    /// Transition length in milliseconds; defaults to
+    /// [`DEFAULT_TRANSITION_MS`]
+    #[prop(optional)]
+    duration: Option<u32>,
+
+    /// CSS class added after `lucide-icon`
+    #[prop(optional, into)]
+    class: Option<String>,
+
+    /// Inline style string
+    #[prop(optional, into)]
+    style: Option<String>,
+
+    /// Width and height
+    #[prop(optional, into)]
+    size: Option<String>,
+
+    /// Stroke width
+    #[prop(optional, into)]
+    stroke_width: Option<String>,
+
+    /// Stroke color
+    #[prop(optional, into)]
+    stroke: Option<String>,
+
+    /// Fill color
+    #[prop(optional, into)]
+    fill: Option<String>,
+) -> impl IntoView {
+    let duration = duration.unwrap_or(DEFAULT_TRANSITION_MS);
+    let attrs = SvgAttributes::new(&IconConfig {
+        class,
+        style,
+        size,
+        stroke_width,
+        stroke,
+        fill,
+        animation: None,
+        filled: false,
+        fill_color: None,
+    });
+
+    let layers = RwSignal::new(vec![Layer {
+        id: 0,
+        icon: ArcRwSignal::new(icon.get_untracked()),
+        state: ArcRwSignal::new(LayerState::Idle),
+    }]);
+    let next_id = StoredValue::new(1);
+
+    Effect::watch(
+        move || icon.get(),
+        move |&next, _, _| {
+            let Some(current) = layers.with_untracked(|layers| layers.last().cloned()) else {
+                return;
+            };
+            let shown = current.icon.get_untracked();
+            if shown == next {
+                return;
+            }
+            if morph && shown.can_morph_into(next) {
+                current.icon.set(next);
+                return;
+            }
+
+            current.state.set(LayerState::Leaving);
+            let id = next_id.get_value();
+            next_id.set_value(id + 1);
+            layers.update(|layers| {
+                layers.push(Layer {
+                    id,
+                    icon: ArcRwSignal::new(next),
+                    state: ArcRwSignal::new(LayerState::Entering),
+                })
+            });
+            set_timeout(
+                move || layers.update(|layers| layers.retain(|layer| layer.id != current.id)),
+                Duration::from_millis(duration.into()),
+            );
+        },
+        false,
+    );
+
+    let mut wrapper_class = format!("lucide-animated {}", transition.class());
+    if morph {
+        wrapper_class.push_str(" lucide-morph");
+    }
+
+    view! {
+        <span
+            class=wrapper_class
+            style=format!("display: inline-grid; --lucide-transition-duration: {duration}ms")
+        >
+            <For each=move || layers.get() key=|layer| layer.id let(layer)>
+                {layer_view(layer, attrs.clone())}
+            </For>
+        </span>
+    }
+}
+
+fn layer_view(layer: Layer, attrs: SvgAttributes) -> impl IntoView {
+    let icon: Signal<IconName> = layer.icon.into();
+    let state: Signal<LayerState> = layer.state.into();
+    let mut style = "grid-area: 1 / 1".to_string();
+    if let Some(ref extra) = attrs.style {
+        style.push_str("; ");
+        style.push_str(extra);
+    }
+    // Built once from the layer's first icon; morphing only ever swaps in an
+    // icon with the same elements, so updating the attributes is enough
+    let children = icon_children(icon, Signal::stored(attrs.shape_fill.clone()));
+
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            width=attrs.width
+            height=attrs.height
+            viewBox="0 0 24 24"
+            fill=attrs.fill
+            stroke=attrs.stroke
+            stroke-width=attrs.stroke_width
+            stroke-linecap="round"
+            stroke-linejoin="round"
+            data-lucide=move || icon.get().as_str()
+            class=attrs.class
+            class:lucide-enter=move || state.get() == LayerState::Entering
+            class:lucide-leave=move || state.get() == LayerState::Leaving
+            aria-hidden=move || (state.get() == LayerState::Leaving).then_some("true")
+            style=style
+        >
+            {children}
+        </svg>
+    }
+}
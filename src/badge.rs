@@ -0,0 +1,283 @@
+//! Icons with a count or status dot in one corner
+
+use crate::render::icon_children;
+use crate::{icon_svg, IconName, LucideProps};
+use leptos::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Default badge color
+pub const DEFAULT_BADGE_COLOR: &str = "#ef4444";
+
+/// Default color of the count inside a badge
+pub const DEFAULT_BADGE_TEXT_COLOR: &str = "white";
+
+/// Largest count shown before it becomes e.g. `99+`, unless overridden
+pub const DEFAULT_BADGE_MAX: u32 = 99;
+
+/// Height of a count badge in viewBox units
+const COUNT_HEIGHT: f64 = 11.0;
+
+/// Diameter of a dot badge in viewBox units
+const DOT_SIZE: f64 = 7.0;
+
+/// Space cleared around the badge, in viewBox units
+const NOTCH_GAP: f64 = 2.0;
+
+/// Corner an [`IconBadge`] sits in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BadgePosition {
+    #[default]
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+}
+
+impl BadgePosition {
+    /// Every position
+    pub const ALL: &'static [BadgePosition] = &[
+        BadgePosition::TopRight,
+        BadgePosition::TopLeft,
+        BadgePosition::BottomRight,
+        BadgePosition::BottomLeft,
+    ];
+
+    /// Kebab-case name, e.g. `"top-right"`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            BadgePosition::TopRight => "top-right",
+            BadgePosition::TopLeft => "top-left",
+            BadgePosition::BottomRight => "bottom-right",
+            BadgePosition::BottomLeft => "bottom-left",
+        }
+    }
+
+    /// Center of the badge in viewBox units; counts sit further into the
+    /// corner than dots so they cover less of the icon
+    fn center(self, dot: bool) -> (f64, f64) {
+        let (near, far) = if dot { (5.0, 19.0) } else { (4.0, 20.0) };
+        match self {
+            BadgePosition::TopRight => (far, near),
+            BadgePosition::TopLeft => (near, near),
+            BadgePosition::BottomRight => (far, far),
+            BadgePosition::BottomLeft => (near, far),
+        }
+    }
+}
+
+/// What the badge shows once hidden badges are filtered out
+#[derive(Clone, PartialEq)]
+enum Badge {
+    Dot,
+    Count(String),
+}
+
+impl Badge {
+    /// Width and height in viewBox units
+    fn size(&self) -> (f64, f64) {
+        match self {
+            Badge::Dot => (DOT_SIZE, DOT_SIZE),
+            Badge::Count(text) => {
+                let width = 5.0 + 5.0 * text.chars().count() as f64;
+                (width.max(COUNT_HEIGHT), COUNT_HEIGHT)
+            }
+        }
+    }
+}
+
+/// `id` for a badge's mask, unique within the page
+///
+/// While hydrating, the server and the client take ids from the hydration
+/// context in the same render order, so a hydrated badge keeps the server's
+/// id. Otherwise a process-wide counter is used, with its own prefix so its
+/// ids can't clash with server-rendered ones.
+fn next_mask_id() -> String {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    #[cfg(any(feature = "ssr", feature = "hydrate"))]
+    if let Some(context) = Owner::current_shared_context()
+        .filter(|context| !context.is_browser() || context.during_hydration())
+    {
+        return format!("lucide-badge-{}", context.next_id().into_inner());
+    }
+    format!("lucide-badge-c{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Pill centered on `center`, grown by `grow` on every side
+fn pill(center: (f64, f64), (width, height): (f64, f64), grow: f64) -> [f64; 5] {
+    let (width, height) = (width + 2.0 * grow, height + 2.0 * grow);
+    [
+        center.0 - width / 2.0,
+        center.1 - height / 2.0,
+        width,
+        height,
+        height / 2.0,
+    ]
+}
+
+/// Icon with a count or dot badge in one corner
+///
+/// Where the badge overlaps the icon, a mask cuts a notch into the strokes,
+/// the way Lucide's own badge icons such as `BellDot` do. Counts of zero hide
+/// the badge, and counts above `max` show as e.g. `99+`.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn Inbox() -> impl IntoView {
+///     let (unread, _) = signal(3);
+///
+///     view! {
+///         <IconBadge icon=IconName::Mail count=unread/>
+///         <IconBadge icon=IconName::Bell dot=true color="#22c55e"/>
+///     }
+/// }
+/// ```
+#[component]
+pub fn IconBadge(
+    /// Icon under the badge
+    icon: IconName,
+
+    /// Number shown in the badge
+    #[prop(optional, into)]
+    count: Option<Signal<u32>>,
+
+    /// Show a dot instead of a count
+    #[prop(optional, into)]
+    dot: Signal<bool>,
+
+    /// Largest count shown before it becomes `{max}+`; defaults to
+    /// [`DEFAULT_BADGE_MAX`]
+    #[prop(optional)]
+    max: Option<u32>,
+
+    /// Corner the badge sits in
+    #[prop(optional)]
+    position: BadgePosition,
+
+    /// Badge color; defaults to [`DEFAULT_BADGE_COLOR`]
+    #[prop(optional, into)]
+    color: Option<String>,
+
+    /// Color of the count; defaults to [`DEFAULT_BADGE_TEXT_COLOR`]
+    #[prop(optional, into)]
+    text_color: Option<String>,
+
+    /// CSS class added after `lucide-icon`
+    #[prop(optional, into)]
+    class: Option<String>,
+
+    /// Inline style string
+    #[prop(optional, into)]
+    style: Option<String>,
+
+    /// Width and height
+    #[prop(optional, into)]
+    size: Option<String>,
+
+    /// Stroke width
+    #[prop(optional, into)]
+    stroke_width: Option<String>,
+
+    /// Stroke color
+    #[prop(optional, into)]
+    stroke: Option<String>,
+) -> impl IntoView {
+    let max = max.unwrap_or(DEFAULT_BADGE_MAX);
+    let color = color.unwrap_or_else(|| DEFAULT_BADGE_COLOR.to_string());
+    let text_color = text_color.unwrap_or_else(|| DEFAULT_BADGE_TEXT_COLOR.to_string());
+    let mask_id = next_mask_id();
+    let mask_url = format!("url(#{mask_id})");
+    let badge = Memo::new(move |_| {
+        if dot.get() {
+            return Some(Badge::Dot);
+        }
+        match count.map(|count| count.get()) {
+            None | Some(0) => None,
+            Some(count) if count > max => Some(Badge::Count(format!("{max}+"))),
+            Some(count) => Some(Badge::Count(count.to_string())),
+        }
+    });
+
+    // Counts may overflow the viewBox in the corner
+    let mut badge_style = "overflow: visible".to_string();
+    if let Some(ref extra) = style {
+        badge_style.push_str("; ");
+        badge_style.push_str(extra);
+    }
+    let props = LucideProps {
+        class,
+        style: Some(badge_style),
+        size,
+        stroke_width,
+        stroke,
+        ..LucideProps::default()
+    };
+
+    let overlay = move || {
+        badge.get().map(|badge| {
+            let dot = badge == Badge::Dot;
+            let center = position.center(dot);
+            let [x, y, width, height, radius] = pill(center, badge.size(), 0.0);
+            let [notch_x, notch_y, notch_width, notch_height, notch_radius] =
+                pill(center, badge.size(), NOTCH_GAP);
+            let text = match badge {
+                Badge::Dot => None,
+                Badge::Count(ref text) => Some(view! {
+                    <text
+                        x=center.0
+                        y=center.1
+                        fill=text_color.clone()
+                        font-size="8"
+                        font-weight="600"
+                        font-family="system-ui, sans-serif"
+                        text-anchor="middle"
+                        dominant-baseline="central"
+                    >
+                        {text.clone()}
+                    </text>
+                }),
+            };
+
+            view! {
+                <defs>
+                    <mask
+                        id=mask_id.clone()
+                        maskUnits="userSpaceOnUse"
+                        x="-12"
+                        y="-12"
+                        width="48"
+                        height="48"
+                        stroke="none"
+                    >
+                        <rect x="-12" y="-12" width="48" height="48" fill="white"/>
+                        <rect
+                            x=notch_x
+                            y=notch_y
+                            width=notch_width
+                            height=notch_height
+                            rx=notch_radius
+                            fill="black"
+                        />
+                    </mask>
+                </defs>
+                <g class="lucide-badge" stroke="none">
+                    <rect x=x y=y width=width height=height rx=radius fill=color.clone()/>
+                    {text}
+                </g>
+            }
+        })
+    };
+
+    let shape_fill = Signal::stored(props.shape_fill());
+    let children = view! {
+        <g mask=move || badge.with(Option::is_some).then(|| mask_url.clone())>
+            {icon_children(Signal::stored(icon), shape_fill)}
+        </g>
+        {overlay}
+    };
+
+    icon_svg(icon.as_str(), props, children)
+}
@@ -0,0 +1,123 @@
+//! Buttons that show only an icon
+
+use crate::render::icon_children;
+use crate::{icon_svg, IconAnimation, IconName, LucideProps};
+use leptos::ev::MouseEvent;
+use leptos::prelude::*;
+
+/// Icon-only `<button>` with an accessible label and a loading state
+///
+/// `label` becomes the button's `aria-label`, since the icon alone has no
+/// text. While `loading`, the icon is swapped for a spinning `LoaderCircle`,
+/// the button gets `aria-busy="true"` and clicks are ignored. The spin comes
+/// from [`LUCIDE_CSS`](crate::LUCIDE_CSS), e.g. via `LucideStyles`.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn SaveButton() -> impl IntoView {
+///     let (saving, set_saving) = signal(false);
+///
+///     view! {
+///         <IconButton
+///             icon=IconName::Save
+///             label="Save"
+///             loading=saving
+///             on_click=move |_| set_saving.set(true)
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn IconButton(
+    /// Icon to show
+    icon: IconName,
+
+    /// Accessible name, used as `aria-label`
+    #[prop(into)]
+    label: String,
+
+    /// Called when the button is clicked, unless it's loading
+    #[prop(optional, into)]
+    on_click: Option<Callback<MouseEvent>>,
+
+    /// Disable the button
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Show a spinner instead of the icon and mark the button busy
+    #[prop(optional, into)]
+    loading: Signal<bool>,
+
+    /// CSS class added after `lucide-button`
+    #[prop(optional, into)]
+    class: Option<String>,
+
+    /// Inline style for the `<button>`
+    #[prop(optional, into)]
+    style: Option<String>,
+
+    /// Icon width and height
+    #[prop(optional, into)]
+    size: Option<String>,
+
+    /// Stroke width
+    #[prop(optional, into)]
+    stroke_width: Option<String>,
+
+    /// Stroke color
+    #[prop(optional, into)]
+    stroke: Option<String>,
+) -> impl IntoView {
+    let mut button_class = "lucide-button".to_string();
+    if let Some(ref class) = class {
+        button_class.push(' ');
+        button_class.push_str(class);
+    }
+
+    let props = LucideProps {
+        size,
+        stroke_width,
+        stroke,
+        ..LucideProps::default()
+    };
+
+    let content = move || {
+        let mut props = props.clone();
+        let shown = if loading.get() {
+            props.animation = Some(IconAnimation::Spin);
+            IconName::LoaderCircle
+        } else {
+            icon
+        };
+        let shape_fill = Signal::stored(props.shape_fill());
+        icon_svg(
+            shown.as_str(),
+            props,
+            icon_children(Signal::stored(shown), shape_fill),
+        )
+    };
+
+    view! {
+        <button
+            type="button"
+            class=button_class
+            style=style
+            aria-label=label
+            aria-busy=move || loading.get().then_some("true")
+            disabled=disabled
+            on:click=move |event| {
+                if loading.get_untracked() {
+                    return;
+                }
+                if let Some(on_click) = on_click {
+                    on_click.run(event);
+                }
+            }
+        >
+            {content}
+        </button>
+    }
+}
@@ -0,0 +1,47 @@
+//! `data:` URIs for CSS `background-image`, `mask-image` and `<img src>`
+
+use crate::{render_icon_svg, IconConfig, IconName};
+use base64::Engine;
+
+/// Characters left as-is when percent-encoding; everything else is escaped
+fn is_uri_safe(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"-._~/:=,;!*'()".contains(&byte)
+}
+
+/// Render an icon as a URL-encoded `data:image/svg+xml,...` URI
+///
+/// The config is baked into the markup. Images can't inherit the page's text
+/// color, so set `stroke` explicitly unless black is what you want.
+///
+/// ```rust
+/// use leptos_lucide_rs::*;
+///
+/// let uri = icon_data_uri(IconName::Heart, &IconConfig::new().stroke("#16a34a"));
+/// assert!(uri.starts_with("data:image/svg+xml,%3Csvg"));
+///
+/// let css = format!("background-image: url(\"{uri}\");");
+/// ```
+pub fn icon_data_uri(icon: IconName, config: &IconConfig) -> String {
+    let svg = render_icon_svg(icon, config);
+    let mut uri = String::with_capacity(svg.len() * 3 / 2 + 19);
+    uri.push_str("data:image/svg+xml,");
+    for byte in svg.bytes() {
+        if is_uri_safe(byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
+/// Render an icon as a base64 `data:image/svg+xml;base64,...` URI
+///
+/// Longer than [`icon_data_uri`], but accepted by consumers that reject
+/// percent-encoded SVG.
+pub fn icon_data_uri_base64(icon: IconName, config: &IconConfig) -> String {
+    let svg = render_icon_svg(icon, config);
+    let mut uri = String::from("data:image/svg+xml;base64,");
+    base64::engine::general_purpose::STANDARD.encode_string(svg, &mut uri);
+    uri
+}
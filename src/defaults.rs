@@ -0,0 +1,13 @@
+//! Default root `<svg>` attributes, shared with the build script's previews
+
+/// Default icon width and height
+pub const DEFAULT_SIZE: &str = "24";
+
+/// Default stroke color
+pub const DEFAULT_STROKE: &str = "currentColor";
+
+/// Default stroke width
+pub const DEFAULT_STROKE_WIDTH: &str = "2";
+
+/// Default fill
+pub const DEFAULT_FILL: &str = "none";
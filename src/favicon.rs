@@ -0,0 +1,108 @@
+//! Favicons and web app manifest icons
+
+#[cfg(feature = "raster")]
+use crate::render_png;
+use crate::{IconConfig, IconName};
+
+/// Square PNG sizes commonly listed in a web app manifest
+#[cfg(feature = "raster")]
+pub const MANIFEST_ICON_SIZES: &[u32] = &[48, 72, 96, 128, 144, 192, 256, 384, 512];
+
+/// Page favicon drawn from a Lucide icon, injected into `<head>` with
+/// `leptos_meta`
+///
+/// The icon is embedded as an SVG data URI, so no extra request or asset is
+/// needed. Requires a `leptos_meta` context, e.g. from `provide_meta_context`.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     leptos_meta::provide_meta_context();
+///
+///     view! {
+///         <Favicon icon=IconName::House color="#2563eb"/>
+///     }
+/// }
+/// ```
+#[cfg(feature = "meta")]
+#[leptos::component]
+pub fn Favicon(
+    /// Icon shown in the browser tab
+    icon: IconName,
+
+    /// Stroke color; browser tabs don't provide `currentColor`
+    #[prop(optional, into)]
+    color: Option<String>,
+
+    /// Stroke width
+    #[prop(optional, into)]
+    stroke_width: Option<String>,
+) -> impl leptos::IntoView {
+    use leptos::prelude::*;
+
+    let mut config = IconConfig::new();
+    if let Some(color) = color {
+        config = config.stroke(color);
+    }
+    if let Some(stroke_width) = stroke_width {
+        config = config.stroke_width(stroke_width);
+    }
+
+    view! {
+        <leptos_meta::Link
+            rel="icon"
+            type_="image/svg+xml"
+            sizes="any"
+            href=crate::icon_data_uri(icon, &config)
+        />
+    }
+}
+
+/// A rasterized icon for a web app manifest's `icons` list
+#[cfg(feature = "raster")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestIcon {
+    /// Width and height in pixels
+    pub size: u32,
+    /// PNG file contents
+    pub png: Vec<u8>,
+}
+
+#[cfg(feature = "raster")]
+impl ManifestIcon {
+    /// Value for the manifest's `sizes` field, e.g. `"192x192"`
+    pub fn sizes(&self) -> String {
+        format!("{0}x{0}", self.size)
+    }
+
+    /// Conventional file name, e.g. `"icon-192x192.png"`
+    pub fn file_name(&self) -> String {
+        format!("icon-{}.png", self.sizes())
+    }
+}
+
+/// Render an icon at every size in [`MANIFEST_ICON_SIZES`]
+///
+/// ```rust
+/// use leptos_lucide_rs::*;
+///
+/// let icons = manifest_icons(IconName::House, &IconConfig::new().stroke("#2563eb"));
+/// for icon in &icons {
+///     // std::fs::write(format!("public/{}", icon.file_name()), &icon.png)?;
+///     assert!(icon.png.starts_with(b"\x89PNG"));
+/// }
+/// assert_eq!(icons.last().unwrap().sizes(), "512x512");
+/// ```
+#[cfg(feature = "raster")]
+pub fn manifest_icons(icon: IconName, config: &IconConfig) -> Vec<ManifestIcon> {
+    MANIFEST_ICON_SIZES
+        .iter()
+        .map(|&size| ManifestIcon {
+            size,
+            png: render_png(icon, size, config),
+        })
+        .collect()
+}
@@ -0,0 +1,375 @@
+//! Icons fetched over HTTP on demand instead of compiled in
+
+use crate::render::{node_children, NODE_ATTRS, NODE_TAGS};
+use crate::{IconAnimation, IconConfig, IconName, IconNode, SvgAttributes};
+use futures::future::{FutureExt, Shared};
+use leptos::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{OnceLock, RwLock};
+
+/// Base URL icons are fetched from unless changed with [`set_icon_base_url`]
+pub const DEFAULT_ICON_BASE_URL: &str = "/icons";
+
+type FetchResult = Result<&'static [IconNode], LazyIconError>;
+
+/// Request shared by everyone asking for an icon while it is in flight
+#[cfg(not(target_arch = "wasm32"))]
+type PendingFetch = Shared<futures::future::BoxFuture<'static, FetchResult>>;
+#[cfg(target_arch = "wasm32")]
+type PendingFetch = Shared<futures::future::LocalBoxFuture<'static, FetchResult>>;
+
+fn base_url() -> &'static RwLock<String> {
+    static BASE_URL: OnceLock<RwLock<String>> = OnceLock::new();
+    BASE_URL.get_or_init(|| RwLock::new(DEFAULT_ICON_BASE_URL.to_string()))
+}
+
+/// Nodes of fetched icons, keyed by URL
+fn cache() -> &'static RwLock<HashMap<String, &'static [IconNode]>> {
+    static CACHE: OnceLock<RwLock<HashMap<String, &'static [IconNode]>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// Requests in flight, keyed by URL
+#[cfg(not(target_arch = "wasm32"))]
+fn with_pending<R>(f: impl FnOnce(&mut HashMap<String, PendingFetch>) -> R) -> R {
+    static PENDING: OnceLock<std::sync::Mutex<HashMap<String, PendingFetch>>> = OnceLock::new();
+    f(&mut PENDING.get_or_init(Default::default).lock().unwrap())
+}
+
+/// Requests in flight, keyed by URL
+#[cfg(target_arch = "wasm32")]
+fn with_pending<R>(f: impl FnOnce(&mut HashMap<String, PendingFetch>) -> R) -> R {
+    thread_local! {
+        static PENDING: std::cell::RefCell<HashMap<String, PendingFetch>> = Default::default();
+    }
+    PENDING.with(|pending| f(&mut pending.borrow_mut()))
+}
+
+/// Set the base URL lazy icons are fetched from, e.g. `"/static/icons"` or
+/// `"https://cdn.example.com/lucide"`
+///
+/// Icons are requested as `{base}/{name}.svg`, e.g. from
+/// [`icon_routes`](crate::icon_routes) or a copy of the Lucide SVGs. Only the
+/// shape elements and geometry attributes Lucide uses are kept from the
+/// responses, so nothing else in them reaches the page.
+///
+/// On native targets requests go through `reqwest`, which this crate builds
+/// without a TLS backend. To fetch `https://` URLs there, enable one in your
+/// application, e.g. `reqwest = { version = "0.12", features = ["rustls-tls"] }`.
+pub fn set_icon_base_url(base: impl Into<String>) {
+    let base = base.into();
+    *base_url().write().unwrap() = base.trim_end_matches('/').to_string();
+}
+
+/// URL an icon is fetched from
+pub fn icon_url(icon: IconName) -> String {
+    format!("{}/{}.svg", base_url().read().unwrap(), icon.as_str())
+}
+
+/// Error fetching a lazy icon
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LazyIconError {
+    /// The request could not be sent or its body read
+    Request(String),
+    /// The server answered with a non-success status
+    Status(u16),
+    /// The response is not an SVG document
+    Invalid(String),
+}
+
+impl fmt::Display for LazyIconError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LazyIconError::Request(message) => write!(f, "icon request failed: {message}"),
+            LazyIconError::Status(status) => write!(f, "icon request returned status {status}"),
+            LazyIconError::Invalid(message) => write!(f, "icon response is not an SVG: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for LazyIconError {}
+
+#[cfg(target_arch = "wasm32")]
+async fn get(url: &str) -> Result<String, LazyIconError> {
+    let request_error = |err: gloo_net::Error| LazyIconError::Request(err.to_string());
+    let response = gloo_net::http::Request::get(url)
+        .send()
+        .await
+        .map_err(request_error)?;
+    if !response.ok() {
+        return Err(LazyIconError::Status(response.status()));
+    }
+    response.text().await.map_err(request_error)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn get(url: &str) -> Result<String, LazyIconError> {
+    let request_error = |err: reqwest::Error| LazyIconError::Request(err.to_string());
+    let response = reqwest::get(url).await.map_err(request_error)?;
+    if !response.status().is_success() {
+        return Err(LazyIconError::Status(response.status().as_u16()));
+    }
+    response.text().await.map_err(request_error)
+}
+
+/// Shape elements of a standalone `<svg>` document, with their geometry
+///
+/// Fetched icons are cached for the life of the process, so their nodes are
+/// leaked once per URL to render them like compiled icons. They never count as
+/// closed, as `DynamicIcon` has no `filled` prop.
+fn parse_nodes(svg: &str) -> FetchResult {
+    let document =
+        roxmltree::Document::parse(svg).map_err(|err| LazyIconError::Invalid(err.to_string()))?;
+    let root = document.root_element();
+    if root.tag_name().name() != "svg" {
+        return Err(LazyIconError::Invalid(format!(
+            "root element is <{}>",
+            root.tag_name().name()
+        )));
+    }
+
+    let nodes: Vec<IconNode> = root
+        .children()
+        .filter_map(|node| {
+            let tag = *NODE_TAGS
+                .iter()
+                .find(|tag| **tag == node.tag_name().name())?;
+            let attrs: Vec<(&'static str, &'static str)> = node
+                .attributes()
+                .filter(|attr| attr.namespace().is_none())
+                .filter_map(|attr| {
+                    let name = *NODE_ATTRS.iter().find(|name| **name == attr.name())?;
+                    Some((name, &*attr.value().to_string().leak()))
+                })
+                .collect();
+            Some(IconNode {
+                tag,
+                attrs: attrs.leak(),
+                closed: false,
+            })
+        })
+        .collect();
+    Ok(nodes.leak())
+}
+
+fn cached_url(url: &str) -> Option<&'static [IconNode]> {
+    cache().read().unwrap().get(url).copied()
+}
+
+/// An icon's nodes if it has already been fetched
+pub fn cached_icon(icon: IconName) -> Option<&'static [IconNode]> {
+    cached_url(&icon_url(icon))
+}
+
+/// Fetch an icon's nodes, or return them from the in-memory cache
+///
+/// Concurrent requests for the same icon share one fetch. Only successful
+/// responses are cached, so failed icons are retried the next time they are
+/// requested.
+pub async fn fetch_icon(icon: IconName) -> FetchResult {
+    let url = icon_url(icon);
+    if let Some(nodes) = cached_url(&url) {
+        return Ok(nodes);
+    }
+
+    let fetch = with_pending(|pending| {
+        // A fetch caches its icon before leaving `pending`, so one that
+        // finished since the check above is seen here
+        if let Some(nodes) = cached_url(&url) {
+            return share(async move { Ok(nodes) });
+        }
+        pending
+            .entry(url.clone())
+            .or_insert_with(|| share(fetch_nodes(url)))
+            .clone()
+    });
+    fetch.await
+}
+
+/// Box a fetch so every request for its icon can await it
+#[cfg(not(target_arch = "wasm32"))]
+fn share(fetch: impl std::future::Future<Output = FetchResult> + Send + 'static) -> PendingFetch {
+    fetch.boxed().shared()
+}
+
+/// Box a fetch so every request for its icon can await it
+#[cfg(target_arch = "wasm32")]
+fn share(fetch: impl std::future::Future<Output = FetchResult> + 'static) -> PendingFetch {
+    fetch.boxed_local().shared()
+}
+
+/// Fetch and parse the icon at `url`, caching it on success
+async fn fetch_nodes(url: String) -> FetchResult {
+    let result = match get(&url).await {
+        Ok(svg) => parse_nodes(&svg),
+        Err(err) => Err(err),
+    };
+    if let Ok(nodes) = result {
+        cache().write().unwrap().insert(url.clone(), nodes);
+    }
+    with_pending(|pending| pending.remove(&url));
+    result
+}
+
+/// Start fetching icons in the background so they render without a loading
+/// state later
+///
+/// Requires a Leptos async executor; icons already cached are skipped.
+pub fn preload_icons(icons: &[IconName]) {
+    for &icon in icons {
+        if cached_icon(icon).is_some() {
+            continue;
+        }
+        let fetch = async move {
+            let _ = fetch_icon(icon).await;
+        };
+
+        #[cfg(target_arch = "wasm32")]
+        leptos::task::spawn_local(fetch);
+        #[cfg(not(target_arch = "wasm32"))]
+        leptos::task::spawn(fetch);
+    }
+}
+
+/// Icon fetched from `{base}/{name}.svg` on first use and cached in memory
+///
+/// Avoids compiling in every icon when the one shown is only known at runtime.
+/// While the request is in flight, an empty `<svg>` of the same size keeps the
+/// layout stable. The fetched shapes are rendered as elements like compiled
+/// icons, never as markup. If the icon can't be fetched, `fallback` is shown
+/// and the error is passed to `on_error`, or logged as a warning without one.
+///
+/// Pages that will be hydrated get the empty `<svg>` from the server and fetch
+/// the icon in the browser. Without hydration, e.g. client-side rendering or
+/// server rendering outside islands, the icon is fetched where it renders, which
+/// on the server needs an absolute [base URL](set_icon_base_url).
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn CategoryRow(icon: IconName) -> impl IntoView {
+///     view! { <DynamicIcon icon=icon size="20"/> }
+/// }
+/// ```
+#[component]
+pub fn DynamicIcon(
+    /// Icon to fetch and show
+    #[prop(into)]
+    icon: Signal<IconName>,
+
+    /// CSS class added after `lucide-icon`
+    #[prop(optional, into)]
+    class: Option<String>,
+
+    /// Inline style string
+    #[prop(optional, into)]
+    style: Option<String>,
+
+    /// Width and height
+    #[prop(optional, into)]
+    size: Option<String>,
+
+    /// Stroke width
+    #[prop(optional, into)]
+    stroke_width: Option<String>,
+
+    /// Stroke color
+    #[prop(optional, into)]
+    stroke: Option<String>,
+
+    /// Fill color
+    #[prop(optional, into)]
+    fill: Option<String>,
+
+    /// Looping animation
+    #[prop(optional)]
+    animation: Option<IconAnimation>,
+
+    /// Shown instead of the icon when it can't be fetched; nothing by default
+    #[prop(optional, into)]
+    fallback: ViewFn,
+
+    /// Called when the icon can't be fetched
+    #[prop(optional, into)]
+    on_error: Option<Callback<LazyIconError>>,
+) -> impl IntoView {
+    let attrs = SvgAttributes::new(&IconConfig {
+        class,
+        style,
+        size,
+        stroke_width,
+        stroke,
+        fill,
+        animation,
+        filled: false,
+        fill_color: None,
+    });
+    // Reported once per request, however often the result is rendered
+    let fetch = move || {
+        let icon = icon.get();
+        async move {
+            let result = fetch_icon(icon).await;
+            if let Err(err) = &result {
+                match on_error {
+                    Some(on_error) => on_error.run(err.clone()),
+                    None => leptos::logging::warn!("{} icon unavailable: {err}", icon.as_str()),
+                }
+            }
+            result
+        }
+    };
+    // Hydrated markup has to match the placeholder the browser starts from, so
+    // icons are only fetched there; otherwise they are fetched where they render
+    let hydrating =
+        Owner::current_shared_context().is_some_and(|context| context.get_is_hydrating());
+    let nodes = if hydrating {
+        let nodes = LocalResource::new(fetch);
+        Signal::derive_local(move || nodes.get())
+    } else {
+        let nodes = AsyncDerived::new_unsync(fetch);
+        Signal::derive_local(move || nodes.get())
+    };
+
+    let svg = move |children: Vec<AnyView>, loading: bool| {
+        let attrs = attrs.clone();
+        view! {
+            <svg
+                xmlns="http://www.w3.org/2000/svg"
+                width=attrs.width
+                height=attrs.height
+                viewBox="0 0 24 24"
+                fill=attrs.fill
+                stroke=attrs.stroke
+                stroke-width=attrs.stroke_width
+                stroke-linecap="round"
+                stroke-linejoin="round"
+                data-lucide=move || icon.get().as_str()
+                class=attrs.class
+                class:lucide-loading=loading
+                style=attrs.style
+            >
+                {children}
+            </svg>
+        }
+    };
+    let placeholder = svg.clone();
+
+    view! {
+        <Suspense fallback=move || placeholder(Vec::new(), true)>
+            {move || {
+                nodes
+                    .get()
+                    .map(|result| match result {
+                        Ok(nodes) => {
+                            let children = node_children(Signal::stored(nodes), Signal::stored(None));
+                            svg(children, false).into_any()
+                        }
+                        Err(_) => fallback.run(),
+                    })
+            }}
+        </Suspense>
+    }
+}
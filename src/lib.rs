@@ -20,7 +20,7 @@
 //! pub fn MyComponent() -> impl IntoView {
 //!     view! {
 //!         <div>
-//!             <Home/>
+//!             <House/>
 //!             <User/>
 //!             <Heart/>
 //!         </div>
@@ -40,7 +40,7 @@
 //!         <div>
 //!             // Using the macro for custom classes
 //!             // TODO:
-//!             // {icon!(Home, class = "text-blue-500 w-6 h-6")}
+//!             // {icon!(House, class = "text-blue-500 w-6 h-6")}
 //!
 //!             // Using the macro for custom sizes
 //!             // TODO:
@@ -57,6 +57,73 @@
 
 use leptos::prelude::*;
 
+mod defaults;
+mod render;
+pub use render::{
+    icon_svg, render_icon_svg, render_icon_svg_by_name, IconNode, LucideProps, SvgAttributes,
+    DEFAULT_FILL, DEFAULT_SIZE, DEFAULT_STROKE, DEFAULT_STROKE_WIDTH, PLACEHOLDER_NODES,
+};
+
+mod data_uri;
+pub use data_uri::{icon_data_uri, icon_data_uri_base64};
+
+mod mask;
+pub use mask::IconMask;
+
+mod animated;
+pub use animated::{AnimatedIcon, IconTransition, DEFAULT_TRANSITION_MS};
+
+mod stack;
+pub use stack::{IconLayer, IconStack};
+
+mod badge;
+pub use badge::{
+    BadgePosition, IconBadge, DEFAULT_BADGE_COLOR, DEFAULT_BADGE_MAX, DEFAULT_BADGE_TEXT_COLOR,
+};
+
+mod button;
+pub use button::IconButton;
+
+mod switch;
+pub use switch::{icon_layout, IconSwitch};
+
+mod styles;
+#[cfg(feature = "meta")]
+pub use styles::{provide_lucide_styles, LucideStyles};
+pub use styles::{IconAnimation, LUCIDE_CSS};
+
+#[cfg(feature = "raster")]
+mod raster;
+#[cfg(feature = "raster")]
+pub use raster::{render_png, MAX_PNG_SIZE};
+
+#[cfg(any(feature = "meta", feature = "raster"))]
+mod favicon;
+#[cfg(feature = "meta")]
+pub use favicon::Favicon;
+#[cfg(feature = "raster")]
+pub use favicon::{manifest_icons, ManifestIcon, MANIFEST_ICON_SIZES};
+
+#[cfg(feature = "axum")]
+mod server;
+#[cfg(feature = "axum")]
+pub use server::icon_routes;
+
+#[cfg(feature = "lazy")]
+mod lazy;
+#[cfg(feature = "lazy")]
+pub use lazy::{
+    cached_icon, fetch_icon, icon_url, preload_icons, set_icon_base_url, DynamicIcon,
+    LazyIconError, DEFAULT_ICON_BASE_URL,
+};
+
+#[cfg(feature = "picker")]
+mod picker;
+#[cfg(feature = "picker")]
+pub use picker::{
+    filter_icons, picker_key_action, picker_visible_range, IconPicker, PickerFocus, PickerKeyAction,
+};
+
 // Include the generated icons module
 #[cfg(leptos_lucide_generated)]
 include!(concat!(env!("OUT_DIR"), "/icons.rs"));
@@ -64,85 +131,167 @@
 // Fallback module when icons aren't generated yet (for IDE support)
 #[cfg(not(leptos_lucide_generated))]
 pub mod fallback {
-    use leptos::*;
+    use leptos::prelude::*;
+
+    /// Fallback House icon for development
+    #[inline(always)]
+    #[allow(non_snake_case)]
+    pub fn House(props: crate::LucideProps) -> impl IntoView {
+        load_icon_fallback("house", props)
+    }
 
-    /// Fallback Home icon for development
+    /// Renamed upstream: `home` is now `house`
+    #[deprecated(note = "use House")]
     #[inline(always)]
     #[allow(non_snake_case)]
-    pub fn Home() -> impl IntoView {
-        load_icon_fallback("home")
+    pub fn Home(props: crate::LucideProps) -> impl IntoView {
+        House(props)
     }
 
     /// Fallback User icon for development
     #[inline(always)]
     #[allow(non_snake_case)]
-    pub fn User() -> impl IntoView {
-        load_icon_fallback("user")
+    pub fn User(props: crate::LucideProps) -> impl IntoView {
+        load_icon_fallback("user", props)
     }
 
     /// Fallback Heart icon for development
     #[inline(always)]
     #[allow(non_snake_case)]
-    pub fn Heart() -> impl IntoView {
-        load_icon_fallback("heart")
+    pub fn Heart(props: crate::LucideProps) -> impl IntoView {
+        load_icon_fallback("heart", props)
     }
 
     /// Fallback Search icon for development
     #[inline(always)]
     #[allow(non_snake_case)]
-    pub fn Search() -> impl IntoView {
-        load_icon_fallback("search")
+    pub fn Search(props: crate::LucideProps) -> impl IntoView {
+        load_icon_fallback("search", props)
     }
 
     /// Fallback Star icon for development
     #[inline(always)]
     #[allow(non_snake_case)]
-    pub fn Star() -> impl IntoView {
-        load_icon_fallback("star")
+    pub fn Star(props: crate::LucideProps) -> impl IntoView {
+        load_icon_fallback("star", props)
     }
 
-    /// Generic fallback icon loader using lucide-svg-rs
-    fn load_icon_fallback(name: &str) -> impl IntoView {
-        view! {
-            <svg
-                class="lucide-icon"
-                xmlns="http://www.w3.org/2000/svg"
-                width="24"
-                height="24"
-                viewBox="0 0 24 24"
-                fill="none"
-                stroke="currentColor"
-                stroke-width="2"
-                stroke-linecap="round"
-                stroke-linejoin="round"
-                data-lucide=name
-                inner_html=move || {
-                    let client = lucide_svg_rs::LucideClient::default();
-                    match client.get_icon_content(&format!("{}.svg", name)) {
-                        Ok(svg_content) => {
-                            // Extract content between <svg> tags
-                            if let (Some(start), Some(end)) = (svg_content.find('>'), svg_content.rfind("</svg>")) {
-                                if start < end {
-                                    return svg_content[start + 1..end].to_string();
-                                }
-                            }
-                            // Ultimate fallback if parsing fails
-                            r#"<path d="M12 2L2 7l10 5 10-5-10-5zM2 17l10 5 10-5M2 12l10 5 10-5"></path>"#.to_string()
-                        }
-                        Err(_) => {
-                            // Ultimate fallback
-                            r#"<path d="M12 2L2 7l10 5 10-5-10-5zM2 17l10 5 10-5M2 12l10 5 10-5"></path>"#.to_string()
-                        }
-                    }
-                }
-            >
-            </svg>
-        }
+    /// Fallback LoaderCircle icon for development
+    #[inline(always)]
+    #[allow(non_snake_case)]
+    pub fn LoaderCircle(props: crate::LucideProps) -> impl IntoView {
+        load_icon_fallback("loader-circle", props)
+    }
+
+    /// Placeholder shape standing in for any icon until code generation runs
+    fn load_icon_fallback(name: &str, props: crate::LucideProps) -> impl IntoView {
+        let d = crate::PLACEHOLDER_NODES[0].attr("d");
+        crate::icon_svg(name.to_string(), props, view! { <path d=d/> })
     }
 
     /// Dynamic icon loader (same as generated version)
     pub fn load_icon(name: &str) -> impl IntoView {
-        load_icon_fallback(name)
+        load_icon_fallback(name, crate::LucideProps::default())
+    }
+
+    /// Number of fallback icon components
+    pub const ICON_COUNT: usize = 6;
+
+    /// `(kebab-case, PascalCase)` names of every fallback icon
+    pub const ALL_ICON_NAMES: &[(&str, &str)] = &[
+        ("heart", "Heart"),
+        ("house", "House"),
+        ("loader-circle", "LoaderCircle"),
+        ("search", "Search"),
+        ("star", "Star"),
+        ("user", "User"),
+    ];
+
+    /// Version of the icon set the fallback components stand in for
+    pub const LUCIDE_VERSION: &str = "fallback";
+
+    /// Fallback icon identifiers for development
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub enum IconName {
+        Heart,
+        House,
+        LoaderCircle,
+        Search,
+        Star,
+        User,
+    }
+
+    impl IconName {
+        /// Every fallback icon, sorted by Lucide name
+        pub const ALL: &'static [IconName] = &[
+            IconName::Heart,
+            IconName::House,
+            IconName::LoaderCircle,
+            IconName::Search,
+            IconName::Star,
+            IconName::User,
+        ];
+
+        /// The kebab-case Lucide name
+        pub const fn as_str(self) -> &'static str {
+            match self {
+                IconName::Heart => "heart",
+                IconName::House => "house",
+                IconName::LoaderCircle => "loader-circle",
+                IconName::Search => "search",
+                IconName::Star => "star",
+                IconName::User => "user",
+            }
+        }
+
+        /// The component name
+        pub const fn component_name(self) -> &'static str {
+            match self {
+                IconName::Heart => "Heart",
+                IconName::House => "House",
+                IconName::LoaderCircle => "LoaderCircle",
+                IconName::Search => "Search",
+                IconName::Star => "Star",
+                IconName::User => "User",
+            }
+        }
+
+        /// Look up an icon by its kebab-case Lucide name
+        pub fn from_name(name: &str) -> Option<Self> {
+            match name {
+                "home" => Some(IconName::House),
+                _ => Self::ALL.iter().copied().find(|icon| icon.as_str() == name),
+            }
+        }
+
+        /// Names Lucide used for this icon before renaming it
+        pub const fn former_names(self) -> &'static [&'static str] {
+            match self {
+                IconName::House => &["home"],
+                _ => &[],
+            }
+        }
+
+        /// Placeholder shape standing in for the icon's child elements
+        pub const fn nodes(self) -> &'static [crate::IconNode] {
+            crate::PLACEHOLDER_NODES
+        }
+
+        /// The category this icon is grouped under
+        pub const fn category(self) -> crate::IconCategory {
+            match self {
+                IconName::Heart | IconName::Star => crate::IconCategory::Shapes,
+                IconName::House | IconName::Search => crate::IconCategory::Navigation,
+                IconName::User => crate::IconCategory::People,
+                IconName::LoaderCircle => crate::IconCategory::Status,
+            }
+        }
+    }
+
+    impl std::fmt::Display for IconName {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
     }
 }
 
@@ -154,6 +303,76 @@ pub fn load_icon(name: &str) -> impl IntoView {
 #[cfg(not(leptos_lucide_generated))]
 pub use fallback::load_icon;
 
+/// Broad grouping used to filter icons, e.g. in the icon picker
+///
+/// Categories are assigned at build time from keywords in each icon name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IconCategory {
+    Status,
+    Arrows,
+    Files,
+    Communication,
+    Media,
+    Text,
+    Charts,
+    Development,
+    Weather,
+    Devices,
+    Navigation,
+    Security,
+    People,
+    Commerce,
+    Layout,
+    Shapes,
+    Other,
+}
+
+impl IconCategory {
+    /// Every category, in display order
+    pub const ALL: &'static [IconCategory] = &[
+        IconCategory::Status,
+        IconCategory::Arrows,
+        IconCategory::Files,
+        IconCategory::Communication,
+        IconCategory::Media,
+        IconCategory::Text,
+        IconCategory::Charts,
+        IconCategory::Development,
+        IconCategory::Weather,
+        IconCategory::Devices,
+        IconCategory::Navigation,
+        IconCategory::Security,
+        IconCategory::People,
+        IconCategory::Commerce,
+        IconCategory::Layout,
+        IconCategory::Shapes,
+        IconCategory::Other,
+    ];
+
+    /// Human readable label
+    pub const fn label(self) -> &'static str {
+        match self {
+            IconCategory::Status => "Status",
+            IconCategory::Arrows => "Arrows",
+            IconCategory::Files => "Files",
+            IconCategory::Communication => "Communication",
+            IconCategory::Media => "Media",
+            IconCategory::Text => "Text",
+            IconCategory::Charts => "Charts",
+            IconCategory::Development => "Development",
+            IconCategory::Weather => "Weather",
+            IconCategory::Devices => "Devices",
+            IconCategory::Navigation => "Navigation",
+            IconCategory::Security => "Security",
+            IconCategory::People => "People",
+            IconCategory::Commerce => "Commerce",
+            IconCategory::Layout => "Layout",
+            IconCategory::Shapes => "Shapes",
+            IconCategory::Other => "Other",
+        }
+    }
+}
+
 /// Icon configuration for customizing appearance
 #[derive(Clone, Debug, Default)]
 pub struct IconConfig {
@@ -169,6 +388,13 @@ pub struct IconConfig {
     pub stroke: Option<String>,
     /// Fill color
     pub fill: Option<String>,
+    /// Looping animation
+    pub animation: Option<IconAnimation>,
+    /// Fill closed shapes
+    pub filled: bool,
+    /// Color for filled shapes; defaults to the stroke color, which hides
+    /// open strokes drawn inside them
+    pub fill_color: Option<String>,
 }
 
 impl IconConfig {
@@ -212,6 +438,24 @@ pub fn fill<S: Into<String>>(mut self, fill: S) -> Self {
         self.fill = Some(fill.into());
         self
     }
+
+    /// Set the animation
+    pub fn animation(mut self, animation: IconAnimation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Fill closed shapes, e.g. for an active star or heart
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Set the color of filled shapes
+    pub fn fill_color<S: Into<String>>(mut self, color: S) -> Self {
+        self.fill_color = Some(color.into());
+        self
+    }
 }
 
 /// Helper component for rendering icons with configuration (enhanced version)
@@ -271,6 +515,7 @@ pub fn Icon<F, V>(
 
         "button" => view! {
             <button
+                type="button"
                 class=wrapper_class.clone()
                 style=move || if wrapper_style.is_empty() {
                     None
@@ -366,15 +611,28 @@ pub fn Icon<F, V>(
 #[macro_export]
 macro_rules! icon {
     ($icon:ident) => {
-        $icon()
+        $icon($crate::LucideProps::default())
     };
     ($icon:ident, $($method:ident($value:expr)),+ $(,)?) => {{
         let config = leptos_lucide_rs::IconConfig::new()$(.$method($value))+;
-        leptos_lucide_rs::Icon(|| $icon(), Some(config), None)
+        let props = $crate::LucideProps::painted(&config);
+        $crate::Icon(
+            $crate::IconProps::builder()
+                .icon(move || $icon(props.clone()))
+                .config(config)
+                .build(),
+        )
     }};
     ($icon:ident, wrapper = $wrapper:expr, $($method:ident($value:expr)),+ $(,)?) => {{
         let config = leptos_lucide_rs::IconConfig::new()$(.$method($value))+;
-        leptos_lucide_rs::Icon(|| $icon(), Some(config), Some($wrapper.to_string()))
+        let props = $crate::LucideProps::painted(&config);
+        $crate::Icon(
+            $crate::IconProps::builder()
+                .icon(move || $icon(props.clone()))
+                .config(config)
+                .wrapper($wrapper.to_string())
+                .build(),
+        )
     }};
 }
 
@@ -0,0 +1,88 @@
+//! Icons drawn with CSS masks instead of inline SVG
+
+use crate::{icon_data_uri, IconConfig, IconName, DEFAULT_SIZE};
+use leptos::prelude::*;
+
+/// CSS length for a size prop; bare numbers are treated as pixels
+pub(crate) fn css_length(size: &str) -> String {
+    if size.parse::<f64>().is_ok() {
+        format!("{size}px")
+    } else {
+        size.to_string()
+    }
+}
+
+/// Icon rendered as a `<span>` masked by the icon's data URI
+///
+/// The visible color is the span's `background-color`, which defaults to
+/// `currentColor`, so it follows `color` and can be transitioned on hover. One
+/// element per icon keeps dense lists and tables cheap to render.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn LikeButton() -> impl IntoView {
+///     view! {
+///         <button class="like">
+///             <IconMask icon=IconName::Heart size="20"/>
+///         </button>
+///     }
+/// }
+/// ```
+#[component]
+pub fn IconMask(
+    /// Icon used as the mask
+    icon: IconName,
+
+    /// Width and height; bare numbers are pixels
+    #[prop(optional, into)]
+    size: Option<String>,
+
+    /// Stroke width of the mask shape
+    #[prop(optional, into)]
+    stroke_width: Option<String>,
+
+    /// CSS class added after `lucide-mask`
+    #[prop(optional, into)]
+    class: Option<String>,
+
+    /// Inline style appended after the mask styles
+    #[prop(optional, into)]
+    style: Option<String>,
+) -> impl IntoView {
+    // Only the mask's alpha matters, so any opaque stroke works
+    let mut config = IconConfig::new().stroke("#000");
+    if let Some(stroke_width) = stroke_width {
+        config = config.stroke_width(stroke_width);
+    }
+    let uri = icon_data_uri(icon, &config);
+    let size = css_length(size.as_deref().unwrap_or(DEFAULT_SIZE));
+
+    let mut mask_class = "lucide-mask".to_string();
+    if let Some(ref class) = class {
+        mask_class.push(' ');
+        mask_class.push_str(class);
+    }
+
+    let mut mask_style = format!(
+        "display: inline-block; width: {size}; height: {size}; \
+         background-color: currentColor; \
+         -webkit-mask: url(\"{uri}\") center / 100% 100% no-repeat; \
+         mask: url(\"{uri}\") center / 100% 100% no-repeat;"
+    );
+    if let Some(ref style) = style {
+        mask_style.push(' ');
+        mask_style.push_str(style);
+    }
+
+    view! {
+        <span
+            class=mask_class
+            style=mask_style
+            aria-hidden="true"
+            data-lucide=icon.as_str()
+        ></span>
+    }
+}
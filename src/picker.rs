@@ -0,0 +1,329 @@
+//! Searchable, virtualized icon picker
+
+use crate::{load_icon, IconCategory, IconName};
+use leptos::prelude::*;
+use std::ops::Range;
+
+/// Extra rows rendered above and below the viewport to hide scroll pop-in
+const OVERSCAN_ROWS: usize = 2;
+
+/// Icons the [`IconPicker`] lists for a search
+///
+/// An icon matches when its name, or one of its
+/// [former names](IconName::former_names), contains every whitespace-separated
+/// term in `query`, ignoring case. An empty query lists every icon, and
+/// `category` restricts the list to a single category.
+///
+/// ```rust
+/// use leptos_lucide_rs::{filter_icons, IconName};
+///
+/// assert!(filter_icons("arrow left", None).contains(&IconName::ArrowLeft));
+/// // `home` is what Lucide called `house` before renaming it
+/// assert!(filter_icons("home", None).contains(&IconName::House));
+/// ```
+pub fn filter_icons(query: &str, category: Option<IconCategory>) -> Vec<IconName> {
+    let query = query.to_lowercase();
+    let terms: Vec<&str> = query.split_whitespace().collect();
+    let matches = |name: &str| terms.iter().all(|term| name.contains(term));
+
+    IconName::ALL
+        .iter()
+        .copied()
+        .filter(|icon| category.is_none_or(|category| icon.category() == category))
+        .filter(|icon| {
+            matches(icon.as_str()) || icon.former_names().iter().any(|name| matches(name))
+        })
+        .collect()
+}
+
+/// Indices of the icons the [`IconPicker`] mounts for a scroll position
+///
+/// `len` icons are laid out `columns` wide in rows `cell_size` pixels tall,
+/// behind a viewport `visible_rows` tall scrolled `scroll_top` pixels down.
+/// The range covers every row inside the viewport plus a couple of rows on
+/// either side. Scrolled past the end, e.g. while the list shrinks under a new
+/// search, it covers the last rows rather than nothing.
+///
+/// ```rust
+/// use leptos_lucide_rs::picker_visible_range;
+///
+/// // 8 rows of 8 icons, with 6 rows visible at the top
+/// assert_eq!(picker_visible_range(0, 40, 6, 8, 64), 0..64);
+/// ```
+pub fn picker_visible_range(
+    scroll_top: usize,
+    cell_size: usize,
+    visible_rows: usize,
+    columns: usize,
+    len: usize,
+) -> Range<usize> {
+    let columns = columns.max(1);
+    let total_rows = len.div_ceil(columns);
+    let window_rows = visible_rows + 2 * OVERSCAN_ROWS;
+    let first_row = (scroll_top / cell_size.max(1))
+        .saturating_sub(OVERSCAN_ROWS)
+        .min(total_rows.saturating_sub(window_rows));
+    let last_row = (first_row + window_rows).min(total_rows);
+    first_row * columns..(last_row * columns).min(len)
+}
+
+/// Part of the [`IconPicker`] that received a key press
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PickerFocus {
+    /// The search box
+    Search,
+    /// The icon grid
+    Grid,
+}
+
+/// What a key press does in the [`IconPicker`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PickerKeyAction {
+    /// Highlight the icon at this index
+    Highlight(usize),
+    /// Select the icon at this index
+    Select(usize),
+}
+
+/// Keyboard behavior of the [`IconPicker`]
+///
+/// `current` is the highlighted index in a list of `len` icons laid out
+/// `columns` wide. The grid moves in two dimensions and jumps to either end
+/// with Home and End. The search box only handles ArrowUp, ArrowDown and
+/// Enter, so the other keys keep editing its text. Returns `None` for keys
+/// the picker leaves to the browser.
+///
+/// ```rust
+/// use leptos_lucide_rs::{picker_key_action, PickerFocus, PickerKeyAction};
+///
+/// let action = picker_key_action(PickerFocus::Grid, "ArrowRight", 0, 20, 8);
+/// assert_eq!(action, Some(PickerKeyAction::Highlight(1)));
+/// assert_eq!(picker_key_action(PickerFocus::Search, "ArrowRight", 0, 20, 8), None);
+/// ```
+pub fn picker_key_action(
+    focus: PickerFocus,
+    key: &str,
+    current: usize,
+    len: usize,
+    columns: usize,
+) -> Option<PickerKeyAction> {
+    if len == 0 {
+        return None;
+    }
+    let columns = columns.max(1);
+    let current = current.min(len - 1);
+    let next = match (focus, key) {
+        (_, "ArrowDown") => (current + columns).min(len - 1),
+        (_, "ArrowUp") => current.saturating_sub(columns),
+        (_, "Enter") => return Some(PickerKeyAction::Select(current)),
+        (PickerFocus::Grid, "ArrowRight") => (current + 1).min(len - 1),
+        (PickerFocus::Grid, "ArrowLeft") => current.saturating_sub(1),
+        (PickerFocus::Grid, "Home") => 0,
+        (PickerFocus::Grid, "End") => len - 1,
+        _ => return None,
+    };
+    Some(PickerKeyAction::Highlight(next))
+}
+
+/// Icon picker with a search box, a category filter and a virtualized grid
+///
+/// Only the rows inside the scroll viewport are mounted, so the full Lucide
+/// set stays smooth to scroll. Arrow keys move the highlighted icon and Enter
+/// selects it; see [`picker_key_action`] for the full keyboard behavior.
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn MenuItemEditor() -> impl IntoView {
+///     let (icon, set_icon) = signal(None::<IconName>);
+///
+///     view! {
+///         <IconPicker on_select=move |name| set_icon.set(Some(name))/>
+///         <p>{move || icon.get().map(|name| name.to_string())}</p>
+///     }
+/// }
+/// ```
+#[component]
+pub fn IconPicker(
+    /// Called with the chosen icon on click or Enter
+    #[prop(into)]
+    on_select: Callback<IconName>,
+
+    /// Number of icons per row
+    #[prop(default = 8)]
+    columns: usize,
+
+    /// Width and height of each grid cell in pixels
+    #[prop(default = 48)]
+    cell_size: usize,
+
+    /// Number of rows visible at once
+    #[prop(default = 6)]
+    visible_rows: usize,
+
+    /// Placeholder text for the search box
+    #[prop(optional, into)]
+    placeholder: Option<String>,
+
+    /// CSS class added to the picker root
+    #[prop(optional, into)]
+    class: Option<String>,
+) -> impl IntoView {
+    let columns = columns.max(1);
+    let cell_size = cell_size.max(1);
+
+    let query = RwSignal::new(String::new());
+    let category = RwSignal::new(None::<IconCategory>);
+    let active = RwSignal::new(0usize);
+    let scroll_top = RwSignal::new(0usize);
+    let grid_ref = NodeRef::<leptos::html::Div>::new();
+
+    let icons = Memo::new(move |_| filter_icons(&query.read(), category.get()));
+
+    let total_rows = move || icons.with(|icons| icons.len().div_ceil(columns));
+
+    // Indices of the icons in the rows currently inside the viewport
+    let visible_icons = move || {
+        let scroll_top = scroll_top.get();
+        icons.with(|icons| {
+            picker_visible_range(scroll_top, cell_size, visible_rows, columns, icons.len())
+                .map(|index| (index, icons[index]))
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let reset = move || {
+        active.set(0);
+        scroll_top.set(0);
+        if let Some(grid) = grid_ref.get_untracked() {
+            grid.set_scroll_top(0);
+        }
+    };
+
+    // Keep the highlighted row inside the viewport after keyboard navigation
+    let scroll_into_view = move |index: usize| {
+        let Some(grid) = grid_ref.get_untracked() else {
+            return;
+        };
+        let row_top = (index / columns) * cell_size;
+        let viewport = visible_rows * cell_size;
+        let current = scroll_top.get_untracked();
+        if row_top < current {
+            grid.set_scroll_top(row_top as i32);
+        } else if row_top + cell_size > current + viewport {
+            grid.set_scroll_top((row_top + cell_size - viewport) as i32);
+        }
+    };
+
+    let on_keydown = move |focus: PickerFocus, ev: leptos::ev::KeyboardEvent| {
+        let len = icons.with_untracked(Vec::len);
+        let current = active.get_untracked();
+        let Some(action) = picker_key_action(focus, &ev.key(), current, len, columns) else {
+            return;
+        };
+        ev.prevent_default();
+        match action {
+            PickerKeyAction::Highlight(next) => {
+                active.set(next);
+                scroll_into_view(next);
+            }
+            PickerKeyAction::Select(index) => {
+                on_select.run(icons.with_untracked(|icons| icons[index]));
+            }
+        }
+    };
+
+    let mut root_class = "lucide-picker".to_string();
+    if let Some(ref class) = class {
+        root_class.push(' ');
+        root_class.push_str(class);
+    }
+
+    view! {
+        <div class=root_class>
+            <div class="lucide-picker-controls" style="display: flex; gap: 0.5rem;">
+                <input
+                    class="lucide-picker-search"
+                    type="search"
+                    placeholder=placeholder.unwrap_or_else(|| "Search icons".to_string())
+                    prop:value=move || query.get()
+                    on:input=move |ev| {
+                        query.set(event_target_value(&ev));
+                        reset();
+                    }
+                    on:keydown=move |ev| on_keydown(PickerFocus::Search, ev)
+                />
+                <select
+                    class="lucide-picker-category"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        category.set(
+                            IconCategory::ALL
+                                .iter()
+                                .copied()
+                                .find(|category| category.label() == value),
+                        );
+                        reset();
+                    }
+                >
+                    <option value="">"All"</option>
+                    {IconCategory::ALL
+                        .iter()
+                        .map(|category| view! { <option value=category.label()>{category.label()}</option> })
+                        .collect_view()}
+                </select>
+            </div>
+            <div
+                class="lucide-picker-grid"
+                role="listbox"
+                tabindex="0"
+                node_ref=grid_ref
+                style=format!(
+                    "position: relative; overflow-y: auto; height: {}px; width: {}px;",
+                    visible_rows * cell_size,
+                    columns * cell_size,
+                )
+                on:scroll=move |_| {
+                    if let Some(grid) = grid_ref.get_untracked() {
+                        scroll_top.set(grid.scroll_top().max(0) as usize);
+                    }
+                }
+                on:keydown=move |ev| on_keydown(PickerFocus::Grid, ev)
+            >
+                <div style=move || format!("position: relative; height: {}px;", total_rows() * cell_size)>
+                    <For
+                        each=visible_icons
+                        key=|(index, icon)| (*index, *icon)
+                        children=move |(index, icon)| {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="lucide-picker-cell"
+                                    class:lucide-picker-active=move || active.get() == index
+                                    role="option"
+                                    aria-selected=move || (active.get() == index).to_string()
+                                    aria-label=icon.as_str()
+                                    title=icon.as_str()
+                                    tabindex="-1"
+                                    style=format!(
+                                        "position: absolute; top: {}px; left: {}px; width: {cell_size}px; height: {cell_size}px; display: flex; align-items: center; justify-content: center;",
+                                        (index / columns) * cell_size,
+                                        (index % columns) * cell_size,
+                                    )
+                                    on:click=move |_| {
+                                        active.set(index);
+                                        on_select.run(icon);
+                                    }
+                                >
+                                    {load_icon(icon.as_str())}
+                                </button>
+                            }
+                        }
+                    />
+                </div>
+            </div>
+        </div>
+    }
+}
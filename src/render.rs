@@ -0,0 +1,431 @@
+//! Root `<svg>` attributes shared by the icon components and the string
+//! renderer
+
+use crate::{IconAnimation, IconConfig, IconName};
+use leptos::prelude::*;
+use std::borrow::Cow;
+use std::fmt::Write;
+
+pub use crate::defaults::{DEFAULT_FILL, DEFAULT_SIZE, DEFAULT_STROKE, DEFAULT_STROKE_WIDTH};
+
+/// Shape rendered by `load_icon` for names that match no icon
+pub const PLACEHOLDER_NODES: &[IconNode] = &[IconNode {
+    tag: "path",
+    attrs: &[(
+        "d",
+        "M12 2L2 7l10 5 10-5-10-5zM2 17l10 5 10-5M2 12l10 5 10-5",
+    )],
+    closed: false,
+}];
+
+/// A child element of an icon's root `<svg>`, e.g. a `<path>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IconNode {
+    /// Element name, e.g. `"path"` or `"circle"`
+    pub tag: &'static str,
+    /// Attributes in source order
+    pub attrs: &'static [(&'static str, &'static str)],
+    /// Whether the element encloses an area: a basic shape, or a path whose
+    /// every subpath ends with `z` or where it started
+    pub closed: bool,
+}
+
+impl IconNode {
+    /// Value of the attribute `name`, if set
+    pub fn attr(&self, name: &str) -> Option<&'static str> {
+        self.attrs
+            .iter()
+            .find(|(attr, _)| *attr == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Whether a `filled` icon paints this element: it's closed and has no
+    /// fill of its own
+    fn takes_shape_fill(&self) -> bool {
+        self.closed && self.attr("fill").is_none()
+    }
+}
+
+/// Indices of `nodes` in the order they're drawn: Lucide's order, except that
+/// a `filled` icon draws the shapes it fills first so fills never cover open
+/// strokes
+pub(crate) fn paint_order(nodes: &[IconNode], filled: bool) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..nodes.len()).collect();
+    if filled {
+        order.sort_by_key(|&index| !nodes[index].takes_shape_fill());
+    }
+    order
+}
+
+/// Fill for the closed shapes of a `filled` icon; matches the stroke unless
+/// `fill_color` is set
+fn shape_fill(filled: bool, fill_color: Option<&str>, stroke: Option<&str>) -> Option<String> {
+    filled.then(|| fill_color.or(stroke).unwrap_or(DEFAULT_STROKE).to_string())
+}
+
+/// Root `<svg>` attributes after applying an [`IconConfig`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SvgAttributes {
+    pub class: String,
+    pub style: Option<String>,
+    pub width: String,
+    pub height: String,
+    pub fill: String,
+    pub stroke: String,
+    pub stroke_width: String,
+    /// Fill for closed shapes, set for `filled` icons
+    pub shape_fill: Option<String>,
+}
+
+impl SvgAttributes {
+    /// Resolve the attributes for `config`, falling back to Lucide's defaults
+    pub fn new(config: &IconConfig) -> Self {
+        let mut class = "lucide-icon".to_string();
+        if let Some(animation) = config.animation {
+            class.push(' ');
+            class.push_str(animation.class());
+        }
+        if let Some(ref extra) = config.class {
+            class.push(' ');
+            class.push_str(extra);
+        }
+
+        let size = config.size.as_deref().unwrap_or(DEFAULT_SIZE);
+
+        Self {
+            class,
+            style: config
+                .style
+                .as_deref()
+                .map(|style| style.trim_end().trim_end_matches(';').to_string()),
+            width: size.to_string(),
+            height: size.to_string(),
+            fill: config.fill.as_deref().unwrap_or(DEFAULT_FILL).to_string(),
+            stroke: config
+                .stroke
+                .as_deref()
+                .unwrap_or(DEFAULT_STROKE)
+                .to_string(),
+            stroke_width: config
+                .stroke_width
+                .as_deref()
+                .unwrap_or(DEFAULT_STROKE_WIDTH)
+                .to_string(),
+            shape_fill: shape_fill(
+                config.filled,
+                config.fill_color.as_deref(),
+                config.stroke.as_deref(),
+            ),
+        }
+    }
+
+    /// Render a standalone `<svg>` document with `nodes` as its children
+    ///
+    /// The markup matches what the icon components render on the server.
+    pub fn render(&self, name: &str, nodes: &[IconNode]) -> String {
+        let mut svg = String::with_capacity(512);
+        svg.push_str(r#"<svg xmlns="http://www.w3.org/2000/svg""#);
+        push_attr(&mut svg, "width", &self.width);
+        push_attr(&mut svg, "height", &self.height);
+        push_attr(&mut svg, "viewBox", "0 0 24 24");
+        push_attr(&mut svg, "fill", &self.fill);
+        push_attr(&mut svg, "stroke", &self.stroke);
+        push_attr(&mut svg, "stroke-width", &self.stroke_width);
+        push_attr(&mut svg, "stroke-linecap", "round");
+        push_attr(&mut svg, "stroke-linejoin", "round");
+        push_attr(&mut svg, "data-lucide", name);
+        push_attr(&mut svg, "class", &self.class);
+        if let Some(ref style) = self.style {
+            // Leptos terminates inline styles with `;` when rendering
+            push_attr(&mut svg, "style", &format!("{style};"));
+        }
+        svg.push('>');
+
+        for index in paint_order(nodes, self.shape_fill.is_some()) {
+            let node = &nodes[index];
+            let _ = write!(svg, "<{}", node.tag);
+            for (attr, value) in node.attrs {
+                push_attr(&mut svg, attr, value);
+            }
+            if let Some(ref fill) = self.shape_fill {
+                if node.takes_shape_fill() {
+                    push_attr(&mut svg, "fill", fill);
+                }
+            }
+            let _ = write!(svg, "></{}>", node.tag);
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+fn push_attr(svg: &mut String, name: &str, value: &str) {
+    svg.push(' ');
+    svg.push_str(name);
+    svg.push_str("=\"");
+    for c in value.chars() {
+        match c {
+            '&' => svg.push_str("&amp;"),
+            '<' => svg.push_str("&lt;"),
+            '>' => svg.push_str("&gt;"),
+            '"' => svg.push_str("&quot;"),
+            _ => svg.push(c),
+        }
+    }
+    svg.push('"');
+}
+
+/// Props accepted by every generated icon component
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn Toolbar() -> impl IntoView {
+///     view! {
+///         <House size="32" stroke_width="1.5"/>
+///         <Heart stroke="crimson" class="liked"/>
+///         <Loader animation=IconAnimation::Spin/>
+///         <Star filled=true stroke="#f59e0b"/>
+///     }
+/// }
+/// ```
+#[derive(leptos::typed_builder_macro::TypedBuilder, Clone, Debug, Default)]
+#[builder(crate_module_path = leptos::typed_builder)]
+pub struct LucideProps {
+    /// CSS class added after `lucide-icon`
+    #[builder(default, setter(strip_option, into))]
+    pub class: Option<String>,
+    /// Inline style string
+    #[builder(default, setter(strip_option, into))]
+    pub style: Option<String>,
+    /// Width and height
+    #[builder(default, setter(strip_option, into))]
+    pub size: Option<String>,
+    /// Stroke width
+    #[builder(default, setter(strip_option, into))]
+    pub stroke_width: Option<String>,
+    /// Stroke color
+    #[builder(default, setter(strip_option, into))]
+    pub stroke: Option<String>,
+    /// Fill color
+    #[builder(default, setter(strip_option, into))]
+    pub fill: Option<String>,
+    /// Looping animation; needs `LucideStyles` or [`LUCIDE_CSS`](crate::LUCIDE_CSS)
+    #[builder(default, setter(strip_option))]
+    pub animation: Option<IconAnimation>,
+    /// Fill the icon's closed shapes, e.g. for an active star or heart
+    #[builder(default)]
+    pub filled: bool,
+    /// Color for `filled` shapes; defaults to the stroke color, which hides
+    /// open strokes drawn inside them, such as the check in `CircleCheck`
+    #[builder(default, setter(strip_option, into))]
+    pub fill_color: Option<String>,
+}
+
+impl LucideProps {
+    /// Props carrying only `config`'s fill settings; the `icon!` macro passes
+    /// these to the icon, as `Icon` applies the rest to its wrapper
+    #[doc(hidden)]
+    pub fn painted(config: &IconConfig) -> Self {
+        LucideProps {
+            fill: config.fill.clone(),
+            filled: config.filled,
+            fill_color: config.fill_color.clone(),
+            ..LucideProps::default()
+        }
+    }
+
+    /// Fill for closed shapes, or `None` unless `filled` is set; used by
+    /// generated components
+    #[doc(hidden)]
+    pub fn shape_fill(&self) -> Option<String> {
+        shape_fill(
+            self.filled,
+            self.fill_color.as_deref(),
+            self.stroke.as_deref(),
+        )
+    }
+}
+
+impl leptos::component::Props for LucideProps {
+    type Builder = LucidePropsBuilder;
+
+    fn builder() -> Self::Builder {
+        LucideProps::builder()
+    }
+}
+
+impl From<LucideProps> for IconConfig {
+    fn from(props: LucideProps) -> Self {
+        IconConfig {
+            class: props.class,
+            style: props.style,
+            size: props.size,
+            stroke_width: props.stroke_width,
+            stroke: props.stroke,
+            fill: props.fill,
+            animation: props.animation,
+            filled: props.filled,
+            fill_color: props.fill_color,
+        }
+    }
+}
+
+impl From<IconConfig> for LucideProps {
+    fn from(config: IconConfig) -> Self {
+        LucideProps {
+            class: config.class,
+            style: config.style,
+            size: config.size,
+            stroke_width: config.stroke_width,
+            stroke: config.stroke,
+            fill: config.fill,
+            animation: config.animation,
+            filled: config.filled,
+            fill_color: config.fill_color,
+        }
+    }
+}
+
+/// Wrap an icon's children in its root `<svg>`; used by generated components
+#[doc(hidden)]
+pub fn icon_svg(
+    name: impl Into<Cow<'static, str>>,
+    props: LucideProps,
+    children: impl IntoView,
+) -> impl IntoView {
+    let name = name.into();
+    let attrs = SvgAttributes::new(&props.into());
+
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            width=attrs.width
+            height=attrs.height
+            viewBox="0 0 24 24"
+            fill=attrs.fill
+            stroke=attrs.stroke
+            stroke-width=attrs.stroke_width
+            stroke-linecap="round"
+            stroke-linejoin="round"
+            data-lucide=name
+            class=attrs.class
+            style=attrs.style
+        >
+            {children}
+        </svg>
+    }
+}
+
+/// Elements [`node_children`] renders; others are skipped
+#[cfg(feature = "lazy")]
+pub(crate) const NODE_TAGS: &[&str] = &[
+    "path", "circle", "ellipse", "rect", "line", "polyline", "polygon",
+];
+
+/// Attributes [`node_children`] renders
+#[cfg(feature = "lazy")]
+pub(crate) const NODE_ATTRS: &[&str] = &[
+    "d", "cx", "cy", "r", "rx", "ry", "x", "y", "width", "height", "x1", "y1", "x2", "y2",
+    "points", "fill",
+];
+
+/// Child elements for `icon`'s nodes, built from the icon shown first
+///
+/// Attributes follow `icon`, so swapping in an icon with the same elements
+/// only updates them. Closed shapes without a fill of their own get
+/// `shape_fill`.
+pub(crate) fn icon_children(
+    icon: Signal<IconName>,
+    shape_fill: Signal<Option<String>>,
+) -> Vec<AnyView> {
+    node_children(Signal::derive(move || icon.get().nodes()), shape_fill)
+}
+
+/// Child elements for `nodes`, built from the nodes and fill shown first
+pub(crate) fn node_children(
+    nodes: Signal<&'static [IconNode]>,
+    shape_fill: Signal<Option<String>>,
+) -> Vec<AnyView> {
+    let first = nodes.get_untracked();
+    paint_order(first, shape_fill.with_untracked(Option::is_some))
+        .into_iter()
+        .map(|index| node_view(nodes, shape_fill, index, first[index].tag))
+        .collect()
+}
+
+/// A child element whose attributes follow the node at `index`
+fn node_view(
+    nodes: Signal<&'static [IconNode]>,
+    shape_fill: Signal<Option<String>>,
+    index: usize,
+    tag: &'static str,
+) -> AnyView {
+    let attr =
+        move |name: &'static str| move || nodes.get().get(index).and_then(|node| node.attr(name));
+    let fill = move || {
+        let node = nodes.get().get(index).copied()?;
+        match node.attr("fill") {
+            Some(fill) => Some(fill.to_string()),
+            None if node.closed => shape_fill.get(),
+            None => None,
+        }
+    };
+
+    match tag {
+        "path" => view! { <path d=attr("d") fill=fill/> }.into_any(),
+        "circle" => view! {
+            <circle cx=attr("cx") cy=attr("cy") r=attr("r") fill=fill/>
+        }
+        .into_any(),
+        "ellipse" => view! {
+            <ellipse cx=attr("cx") cy=attr("cy") rx=attr("rx") ry=attr("ry") fill=fill/>
+        }
+        .into_any(),
+        "rect" => view! {
+            <rect
+                x=attr("x")
+                y=attr("y")
+                width=attr("width")
+                height=attr("height")
+                rx=attr("rx")
+                ry=attr("ry")
+                fill=fill
+            />
+        }
+        .into_any(),
+        "line" => view! {
+            <line x1=attr("x1") y1=attr("y1") x2=attr("x2") y2=attr("y2")/>
+        }
+        .into_any(),
+        "polyline" => view! { <polyline points=attr("points") fill=fill/> }.into_any(),
+        "polygon" => view! { <polygon points=attr("points") fill=fill/> }.into_any(),
+        _ => ().into_any(),
+    }
+}
+
+/// Render an icon to a standalone `<svg>` string
+///
+/// Useful outside of Leptos, e.g. for emails or PDF templates, as it needs no
+/// reactive owner.
+///
+/// ```rust
+/// use leptos_lucide_rs::*;
+///
+/// let svg = render_icon_svg(IconName::House, &IconConfig::new().size("32").stroke("#333"));
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.contains(r#"width="32""#));
+/// ```
+pub fn render_icon_svg(icon: IconName, config: &IconConfig) -> String {
+    SvgAttributes::new(config).render(icon.as_str(), icon.nodes())
+}
+
+/// Render an icon looked up by its Lucide name to a standalone `<svg>` string
+///
+/// Returns `None` when no icon with that name was generated.
+pub fn render_icon_svg_by_name(name: &str, config: &IconConfig) -> Option<String> {
+    IconName::from_name(name).map(|icon| render_icon_svg(icon, config))
+}
@@ -0,0 +1,184 @@
+//! Axum routes serving icons as standalone SVG files
+
+use crate::{render_icon_svg, IconConfig, IconName};
+use ::axum::{
+    extract::{Path, Query},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use serde::Deserialize;
+
+/// `Cache-Control` for icon responses; a given URL always renders the same
+/// bytes for a given build
+const CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Most suggestions listed in a 404 response
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Query parameters accepted by the icon route
+#[derive(Debug, Default, Deserialize)]
+struct IconQuery {
+    size: Option<String>,
+    color: Option<String>,
+    stroke_width: Option<String>,
+}
+
+/// Routes serving `GET /icons/{name}.svg?size=&color=&stroke_width=`
+///
+/// Icons come from the embedded node data, so no files need to be deployed.
+/// Responses carry a strong `ETag` and immutable caching headers, and unknown
+/// names return `404` listing similarly named icons.
+///
+/// ```rust,no_run
+/// use axum::Router;
+///
+/// # async fn run() {
+/// let app: Router = Router::new().merge(leptos_lucide_rs::icon_routes());
+/// let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+/// axum::serve(listener, app).await.unwrap();
+/// # }
+/// ```
+pub fn icon_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new().route("/icons/{file}", get(serve_icon))
+}
+
+async fn serve_icon(
+    Path(file): Path<String>,
+    Query(query): Query<IconQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let Some(name) = file.strip_suffix(".svg") else {
+        return not_found(&file);
+    };
+    let Some(icon) = IconName::from_name(name) else {
+        return not_found(name);
+    };
+
+    let mut config = IconConfig::new();
+    if let Some(size) = query.size {
+        if !is_positive_number(&size) {
+            return bad_request("`size` must be a positive number");
+        }
+        config = config.size(size);
+    }
+    if let Some(stroke_width) = query.stroke_width {
+        if !is_positive_number(&stroke_width) {
+            return bad_request("`stroke_width` must be a positive number");
+        }
+        config = config.stroke_width(stroke_width);
+    }
+    if let Some(color) = query.color {
+        config = config.stroke(color);
+    }
+
+    let svg = render_icon_svg(icon, &config);
+    let etag = format!("\"{:016x}\"", fnv1a(svg.as_bytes()));
+    let etag = HeaderValue::from_str(&etag).expect("hex ETag is a valid header value");
+
+    let cached = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(|tag| tag.trim())
+                // If-None-Match uses weak comparison
+                .map(|tag| tag.strip_prefix("W/").unwrap_or(tag))
+                .any(|tag| tag == etag || tag == "*")
+        });
+
+    let cache_headers = [
+        (header::ETAG, etag),
+        (
+            header::CACHE_CONTROL,
+            HeaderValue::from_static(CACHE_CONTROL),
+        ),
+    ];
+
+    if cached {
+        (StatusCode::NOT_MODIFIED, cache_headers).into_response()
+    } else {
+        (
+            cache_headers,
+            [(header::CONTENT_TYPE, "image/svg+xml; charset=utf-8")],
+            svg,
+        )
+            .into_response()
+    }
+}
+
+fn is_positive_number(value: &str) -> bool {
+    value
+        .parse::<f64>()
+        .is_ok_and(|number| number.is_finite() && number > 0.0)
+}
+
+fn bad_request(message: &str) -> Response {
+    (StatusCode::BAD_REQUEST, message.to_string()).into_response()
+}
+
+fn not_found(name: &str) -> Response {
+    let suggestions = suggest_icons(name);
+    let mut body = format!("unknown icon `{name}`");
+    if !suggestions.is_empty() {
+        body.push_str("; did you mean: ");
+        body.push_str(&suggestions.join(", "));
+    }
+    (StatusCode::NOT_FOUND, body).into_response()
+}
+
+/// Icon names closest to `name`, best match first
+fn suggest_icons(name: &str) -> Vec<&'static str> {
+    let name = name.to_lowercase();
+    let max_distance = (name.len() / 3).max(1);
+
+    let mut scored: Vec<(usize, &'static str)> = IconName::ALL
+        .iter()
+        .filter_map(|icon| {
+            let candidate = icon.as_str();
+            let distance = edit_distance(&name, candidate);
+            if distance <= max_distance {
+                Some((distance, candidate))
+            } else if name.len() >= 3 && candidate.contains(name.as_str()) {
+                Some((max_distance + 1, candidate))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    scored.sort_by_key(|&(distance, candidate)| (distance, candidate.len(), candidate));
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Levenshtein distance between two ASCII icon names
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, &ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// 64-bit FNV-1a; stable across builds and platforms, unlike `DefaultHasher`
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
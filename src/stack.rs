@@ -0,0 +1,148 @@
+//! Several icons layered in one box
+
+use crate::mask::css_length;
+use crate::render::icon_children;
+use crate::{icon_svg, IconName, LucideProps, DEFAULT_SIZE};
+use leptos::prelude::*;
+
+/// A fraction as a CSS percentage, without float noise like `10.000000000000002%`
+fn percent(fraction: f64) -> String {
+    format!("{}%", (fraction * 10_000.0).round() / 100.0)
+}
+
+/// Box that layers its [`IconLayer`] children on top of each other
+///
+/// Every layer fills the box and is centered in it, so icons line up without
+/// any CSS; later layers are drawn on top.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn Blocked() -> impl IntoView {
+///     view! {
+///         <IconStack size="32">
+///             <IconLayer icon=IconName::Camera scale=0.6/>
+///             <IconLayer icon=IconName::Ban color="#ef4444"/>
+///         </IconStack>
+///     }
+/// }
+/// ```
+#[component]
+pub fn IconStack(
+    /// Width and height of the box; bare numbers are pixels
+    #[prop(optional, into)]
+    size: Option<String>,
+
+    /// CSS class added after `lucide-stack`
+    #[prop(optional, into)]
+    class: Option<String>,
+
+    /// Inline style appended after the layout styles
+    #[prop(optional, into)]
+    style: Option<String>,
+
+    /// The layers, usually [`IconLayer`]s
+    children: Children,
+) -> impl IntoView {
+    let size = css_length(size.as_deref().unwrap_or(DEFAULT_SIZE));
+
+    let mut stack_class = "lucide-stack".to_string();
+    if let Some(ref class) = class {
+        stack_class.push(' ');
+        stack_class.push_str(class);
+    }
+
+    let mut stack_style = format!(
+        "display: inline-grid; grid-template: 100% / 100%; \
+         width: {size}; height: {size}; vertical-align: middle;"
+    );
+    if let Some(ref style) = style {
+        stack_style.push(' ');
+        stack_style.push_str(style);
+    }
+
+    view! {
+        <span class=stack_class style=stack_style>
+            {children()}
+        </span>
+    }
+}
+
+/// One icon in an [`IconStack`]
+///
+/// `scale` shrinks or grows the icon around the center of the stack, and the
+/// offsets move it by a fraction of the stack's size, e.g. `offset_x=0.25`
+/// moves it a quarter of the box to the right.
+#[component]
+pub fn IconLayer(
+    /// Icon to draw
+    icon: IconName,
+
+    /// Size relative to the stack; defaults to `1.0`
+    #[prop(optional)]
+    scale: Option<f64>,
+
+    /// Horizontal offset as a fraction of the stack's width
+    #[prop(optional)]
+    offset_x: f64,
+
+    /// Vertical offset as a fraction of the stack's height
+    #[prop(optional)]
+    offset_y: f64,
+
+    /// CSS color; the stroke and a `currentColor` fill follow it
+    #[prop(optional, into)]
+    color: Option<String>,
+
+    /// Stroke width, before scaling
+    #[prop(optional, into)]
+    stroke_width: Option<String>,
+
+    /// Fill color
+    #[prop(optional, into)]
+    fill: Option<String>,
+
+    /// CSS class added after `lucide-icon`
+    #[prop(optional, into)]
+    class: Option<String>,
+) -> impl IntoView {
+    let mut transforms = Vec::new();
+    if offset_x != 0.0 || offset_y != 0.0 {
+        transforms.push(format!(
+            "translate({}, {})",
+            percent(offset_x),
+            percent(offset_y)
+        ));
+    }
+    if let Some(scale) = scale.filter(|scale| *scale != 1.0) {
+        transforms.push(format!("scale({scale})"));
+    }
+
+    let mut style = "grid-area: 1 / 1".to_string();
+    if !transforms.is_empty() {
+        style.push_str("; transform: ");
+        style.push_str(&transforms.join(" "));
+    }
+    if let Some(color) = color {
+        style.push_str("; color: ");
+        style.push_str(&color);
+    }
+
+    let props = LucideProps {
+        class,
+        style: Some(style),
+        size: Some("100%".to_string()),
+        stroke_width,
+        fill,
+        ..LucideProps::default()
+    };
+
+    let shape_fill = Signal::stored(props.shape_fill());
+    icon_svg(
+        icon.as_str(),
+        props,
+        icon_children(Signal::stored(icon), shape_fill),
+    )
+}
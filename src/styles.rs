@@ -0,0 +1,347 @@
+//! Built-in icon animations and the stylesheet that defines them
+
+/// Looping animation applied to an icon through a `lucide-*` class
+///
+/// The keyframes come from [`LUCIDE_CSS`], injected with `LucideStyles` or
+/// added to your own stylesheet.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn Saving() -> impl IntoView {
+///     view! { <LoaderCircle animation=IconAnimation::Spin/> }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IconAnimation {
+    /// Full turn every second, e.g. for loaders
+    Spin,
+    /// Fade out and back in
+    Pulse,
+    /// Hop up and down
+    Bounce,
+    /// Grow and fade away, like a radar ping
+    Ping,
+}
+
+impl IconAnimation {
+    /// Every animation
+    pub const ALL: &'static [IconAnimation] = &[
+        IconAnimation::Spin,
+        IconAnimation::Pulse,
+        IconAnimation::Bounce,
+        IconAnimation::Ping,
+    ];
+
+    /// Class added to the icon's `<svg>`, e.g. `"lucide-spin"`
+    pub const fn class(self) -> &'static str {
+        match self {
+            IconAnimation::Spin => "lucide-spin",
+            IconAnimation::Pulse => "lucide-pulse",
+            IconAnimation::Bounce => "lucide-bounce",
+            IconAnimation::Ping => "lucide-ping",
+        }
+    }
+}
+
+/// Base `.lucide-icon`/`.lucide-wrapper`/`.lucide-button` rules and the
+/// keyframes behind [`IconAnimation`] and
+/// [`AnimatedIcon`](crate::AnimatedIcon)'s transitions
+///
+/// Animations are turned off for visitors who prefer reduced motion.
+pub const LUCIDE_CSS: &str = r#".lucide-icon {
+  flex-shrink: 0;
+  vertical-align: middle;
+}
+
+.lucide-wrapper {
+  display: inline-flex;
+  align-items: center;
+  justify-content: center;
+}
+
+.lucide-button {
+  display: inline-flex;
+  align-items: center;
+  justify-content: center;
+  cursor: pointer;
+}
+
+.lucide-button:disabled {
+  cursor: not-allowed;
+  opacity: 0.5;
+}
+
+.lucide-button[aria-busy="true"] {
+  cursor: progress;
+}
+
+.lucide-spin {
+  transform-origin: center;
+  animation: lucide-spin 1s linear infinite;
+}
+
+.lucide-pulse {
+  animation: lucide-pulse 2s cubic-bezier(0.4, 0, 0.6, 1) infinite;
+}
+
+.lucide-bounce {
+  animation: lucide-bounce 1s infinite;
+}
+
+.lucide-ping {
+  transform-origin: center;
+  animation: lucide-ping 1s cubic-bezier(0, 0, 0.2, 1) infinite;
+}
+
+@keyframes lucide-spin {
+  to {
+    transform: rotate(360deg);
+  }
+}
+
+@keyframes lucide-pulse {
+  50% {
+    opacity: 0.5;
+  }
+}
+
+@keyframes lucide-bounce {
+  0%,
+  100% {
+    transform: translateY(-25%);
+    animation-timing-function: cubic-bezier(0.8, 0, 1, 1);
+  }
+  50% {
+    transform: none;
+    animation-timing-function: cubic-bezier(0, 0, 0.2, 1);
+  }
+}
+
+@keyframes lucide-ping {
+  75%,
+  100% {
+    transform: scale(2);
+    opacity: 0;
+  }
+}
+
+.lucide-animated > .lucide-enter,
+.lucide-animated > .lucide-leave {
+  transform-origin: center;
+  animation-duration: var(--lucide-transition-duration, 300ms);
+  animation-fill-mode: both;
+}
+
+.lucide-animated > .lucide-enter {
+  animation-timing-function: ease-out;
+}
+
+.lucide-animated > .lucide-leave {
+  animation-timing-function: ease-in;
+}
+
+.lucide-crossfade > .lucide-enter {
+  animation-name: lucide-fade-in;
+}
+
+.lucide-crossfade > .lucide-leave {
+  animation-name: lucide-fade-out;
+}
+
+.lucide-rotate > .lucide-enter {
+  animation-name: lucide-rotate-in;
+}
+
+.lucide-rotate > .lucide-leave {
+  animation-name: lucide-rotate-out;
+}
+
+.lucide-scale > .lucide-enter {
+  animation-name: lucide-scale-in;
+}
+
+.lucide-scale > .lucide-leave {
+  animation-name: lucide-scale-out;
+}
+
+.lucide-morph > svg > * {
+  transition-property: d, cx, cy, r, rx, ry, x, y, width, height;
+  transition-duration: var(--lucide-transition-duration, 300ms);
+  transition-timing-function: ease-in-out;
+}
+
+@keyframes lucide-fade-in {
+  from {
+    opacity: 0;
+  }
+}
+
+@keyframes lucide-fade-out {
+  to {
+    opacity: 0;
+  }
+}
+
+@keyframes lucide-rotate-in {
+  from {
+    opacity: 0;
+    transform: rotate(-90deg);
+  }
+}
+
+@keyframes lucide-rotate-out {
+  to {
+    opacity: 0;
+    transform: rotate(90deg);
+  }
+}
+
+@keyframes lucide-scale-in {
+  from {
+    opacity: 0;
+    transform: scale(0.5);
+  }
+}
+
+@keyframes lucide-scale-out {
+  to {
+    opacity: 0;
+    transform: scale(0.5);
+  }
+}
+
+@media (prefers-reduced-motion: reduce) {
+  .lucide-spin,
+  .lucide-pulse,
+  .lucide-bounce,
+  .lucide-ping,
+  .lucide-animated > .lucide-enter {
+    animation: none;
+  }
+
+  .lucide-animated > .lucide-leave {
+    animation: none;
+    visibility: hidden;
+  }
+
+  .lucide-morph > svg > * {
+    transition: none;
+  }
+}
+"#;
+
+/// `id` of the injected `<style>`
+#[cfg(feature = "meta")]
+const STYLE_ID: &str = "leptos-lucide-styles";
+
+/// Live `LucideStyles` instances of an app, in mount order
+///
+/// The first one renders the `<style>`; when it unmounts, the next one takes
+/// over.
+#[cfg(feature = "meta")]
+#[derive(Clone)]
+struct StylesRegistry {
+    instances: leptos::prelude::ArcRwSignal<Vec<u64>>,
+    first: leptos::prelude::ArcMemo<Option<u64>>,
+    next_id: std::sync::Arc<std::sync::atomic::AtomicU64>,
+}
+
+#[cfg(feature = "meta")]
+impl StylesRegistry {
+    fn new() -> Self {
+        use leptos::prelude::*;
+
+        let instances = ArcRwSignal::new(Vec::new());
+        let first = ArcMemo::new({
+            let instances = instances.clone();
+            move |_| instances.with(|instances: &Vec<u64>| instances.first().copied())
+        });
+        Self {
+            instances,
+            first,
+            next_id: Default::default(),
+        }
+    }
+}
+
+/// Let every [`LucideStyles`] in the app share one `<style>`
+///
+/// Call it at the app root next to `provide_meta_context`. Without it, each
+/// `LucideStyles` only shares its `<style>` with the ones rendered below it.
+#[cfg(feature = "meta")]
+pub fn provide_lucide_styles() {
+    use leptos::prelude::*;
+
+    if use_context::<StylesRegistry>().is_none() {
+        provide_context(StylesRegistry::new());
+    }
+}
+
+/// Inject [`LUCIDE_CSS`] into `<head>` with `leptos_meta`
+///
+/// Only the first `LucideStyles` mounted in an app adds a `<style>`; later
+/// ones render nothing, so components can include it without coordinating. If
+/// the one holding the `<style>` unmounts, the next one still mounted adds it.
+/// Requires a `leptos_meta` context, e.g. from `provide_meta_context`, and
+/// [`provide_lucide_styles`] at the app root to share the `<style>` across
+/// the whole app.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     leptos_meta::provide_meta_context();
+///     provide_lucide_styles();
+///
+///     view! {
+///         <LucideStyles/>
+///         <Loader animation=IconAnimation::Spin/>
+///     }
+/// }
+/// ```
+#[cfg(feature = "meta")]
+#[leptos::component]
+pub fn LucideStyles(
+    /// Nonce for a Content Security Policy; defaults to the one from
+    /// Leptos' `use_nonce`, if any
+    #[prop(optional, into)]
+    nonce: Option<String>,
+) -> impl leptos::IntoView {
+    use leptos::either::Either;
+    use leptos::prelude::*;
+    use std::sync::atomic::Ordering;
+
+    provide_lucide_styles();
+    let registry = expect_context::<StylesRegistry>();
+    let id = registry.next_id.fetch_add(1, Ordering::Relaxed);
+    registry.instances.update(|instances| instances.push(id));
+    on_cleanup({
+        let instances = registry.instances.clone();
+        move || instances.update(|instances| instances.retain(|&other| other != id))
+    });
+
+    let first = registry.first;
+    move || {
+        if first.get() != Some(id) {
+            return None;
+        }
+        // Without a nonce, `Style` falls back to `use_nonce` itself
+        Some(match nonce.clone() {
+            Some(nonce) => Either::Left(view! {
+                <leptos_meta::Style id=STYLE_ID nonce=nonce>
+                    {LUCIDE_CSS}
+                </leptos_meta::Style>
+            }),
+            None => Either::Right(view! {
+                <leptos_meta::Style id=STYLE_ID>
+                    {LUCIDE_CSS}
+                </leptos_meta::Style>
+            }),
+        })
+    }
+}
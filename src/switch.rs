@@ -0,0 +1,124 @@
+//! Icons picked from a reactive value
+
+use crate::render::{icon_children, paint_order};
+use crate::{IconConfig, IconName, SvgAttributes};
+use leptos::prelude::*;
+
+impl IconName {
+    /// Whether `other` is drawn with the same elements in the same order, so
+    /// swapping it in only changes attributes
+    ///
+    /// ```rust
+    /// use leptos_lucide_rs::IconName;
+    ///
+    /// assert!(IconName::Minus.same_elements(IconName::Slash));
+    /// assert!(!IconName::Minus.same_elements(IconName::Circle));
+    /// ```
+    pub fn same_elements(self, other: IconName) -> bool {
+        self.nodes().len() == other.nodes().len()
+            && self
+                .nodes()
+                .iter()
+                .zip(other.nodes())
+                .all(|(from, to)| from.tag == to.tag)
+    }
+}
+
+/// Icon whose elements an [`IconSwitch`] keeps mounted while `icon` changes
+///
+/// Changes only when the new icon has different elements (see
+/// [`IconName::same_elements`]); otherwise the mounted children stay and just
+/// take the new icon's attributes.
+pub fn icon_layout(icon: Signal<IconName>) -> Memo<IconName> {
+    Memo::new(move |previous: Option<&IconName>| {
+        let next = icon.get();
+        match previous {
+            Some(&previous) if previous.same_elements(next) => previous,
+            _ => next,
+        }
+    })
+}
+
+/// Icon chosen by mapping a reactive `value` to an icon and its config
+///
+/// Each attribute is memoized, so when `value` changes only the attributes
+/// that differ are updated; icons with the same elements, such as `Heart` with
+/// filled and outlined, are updated in place rather than re-rendered.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_lucide_rs::*;
+///
+/// #[component]
+/// pub fn LikeButton() -> impl IntoView {
+///     let (liked, set_liked) = signal(false);
+///
+///     view! {
+///         <button on:click=move |_| set_liked.update(|liked| *liked = !*liked)>
+///             <IconSwitch
+///                 value=liked
+///                 map=|liked: &bool| {
+///                     if *liked {
+///                         (IconName::Heart, IconConfig::new().filled(true).class("liked"))
+///                     } else {
+///                         (IconName::Heart, IconConfig::new())
+///                     }
+///                 }
+///             />
+///         </button>
+///     }
+/// }
+/// ```
+#[component]
+pub fn IconSwitch<T, F>(
+    /// Value the icon follows
+    #[prop(into)]
+    value: Signal<T>,
+
+    /// Icon and config to show for a value
+    map: F,
+) -> impl IntoView
+where
+    T: Send + Sync + 'static,
+    F: Fn(&T) -> (IconName, IconConfig) + Send + Sync + 'static,
+{
+    let resolved = Memo::new(move |_| {
+        let (icon, config) = value.with(&map);
+        (icon, SvgAttributes::new(&config))
+    });
+    let icon = Memo::new(move |_| resolved.with(|(icon, _)| *icon));
+    let attr = move |field: fn(&SvgAttributes) -> String| {
+        Memo::new(move |_| resolved.with(|(_, attrs)| field(attrs)))
+    };
+    let style = Memo::new(move |_| resolved.with(|(_, attrs)| attrs.style.clone()));
+    let shape_fill = Memo::new(move |_| resolved.with(|(_, attrs)| attrs.shape_fill.clone()));
+
+    let layout = icon_layout(icon.into());
+    // Filling shapes can change the order the children are drawn in
+    let order =
+        Memo::new(move |_| paint_order(icon.get().nodes(), shape_fill.with(Option::is_some)));
+    let children = move || {
+        layout.track();
+        order.track();
+        icon_children(icon.into(), shape_fill.into())
+    };
+
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            width=attr(|attrs| attrs.width.clone())
+            height=attr(|attrs| attrs.height.clone())
+            viewBox="0 0 24 24"
+            fill=attr(|attrs| attrs.fill.clone())
+            stroke=attr(|attrs| attrs.stroke.clone())
+            stroke-width=attr(|attrs| attrs.stroke_width.clone())
+            stroke-linecap="round"
+            stroke-linejoin="round"
+            data-lucide=move || icon.get().as_str()
+            class=attr(|attrs| attrs.class.clone())
+            style=style
+        >
+            {children}
+        </svg>
+    }
+}
@@ -0,0 +1,87 @@
+//! `AnimatedIcon` server rendering and the morph compatibility check.
+//!
+//! Run with `cargo test --features ssr`.
+#![cfg(feature = "ssr")]
+
+mod common;
+
+use common::{inner, ssr, without_markers};
+use leptos::prelude::*;
+use leptos_lucide_rs::*;
+
+#[test]
+fn renders_the_initial_icon_without_a_transition() {
+    let html = ssr(|| {
+        view! { <AnimatedIcon icon=IconName::House transition=IconTransition::Scale/> }.to_html()
+    });
+
+    assert!(
+        html.starts_with(r#"<span class="lucide-animated lucide-scale""#),
+        "{html}"
+    );
+    assert_eq!(html.matches("<svg").count(), 1, "{html}");
+    assert!(html.contains(r#"data-lucide="house""#), "{html}");
+    assert!(!html.contains("lucide-enter"), "{html}");
+    assert!(!html.contains("lucide-leave"), "{html}");
+}
+
+#[test]
+fn renders_the_same_shapes_as_the_icon() {
+    for icon in [IconName::House, IconName::Heart, IconName::Search] {
+        let html = ssr(|| view! { <AnimatedIcon icon=icon morph=true/> }.to_html());
+        let expected = render_icon_svg(icon, &IconConfig::default());
+        let svg = &html[html.find("<svg").unwrap()..html.rfind("</span>").unwrap()];
+        assert_eq!(without_markers(inner(svg)), inner(&expected), "{icon}");
+    }
+}
+
+#[test]
+fn applies_props_and_duration() {
+    let html = ssr(|| {
+        view! {
+            <AnimatedIcon icon=IconName::Play size="32" class="toggle" duration=150 morph=true/>
+        }
+        .to_html()
+    });
+
+    assert!(html.contains("lucide-morph"), "{html}");
+    assert!(
+        html.contains("--lucide-transition-duration: 150ms"),
+        "{html}"
+    );
+    assert!(html.contains(r#"width="32""#), "{html}");
+    assert!(html.contains(r#"class="lucide-icon toggle""#), "{html}");
+}
+
+#[test]
+fn morphs_only_between_compatible_icons() {
+    assert!(IconName::ChevronLeft.can_morph_into(IconName::ChevronRight));
+    assert!(IconName::ChevronUp.can_morph_into(IconName::ChevronDown));
+    assert!(!IconName::Menu.can_morph_into(IconName::X));
+    assert!(!IconName::Play.can_morph_into(IconName::Pause));
+
+    for &icon in IconName::ALL {
+        assert!(icon.can_morph_into(icon), "{icon}");
+    }
+}
+
+#[test]
+fn morphs_only_geometry_css_can_transition() {
+    // Circle and rect geometry are CSS properties
+    assert!(IconName::CircleSmall.can_morph_into(IconName::Circle));
+    // Line endpoints and polygon points aren't, so these would jump
+    assert!(!IconName::ChartNoAxesColumn.can_morph_into(IconName::ChartNoAxesColumnIncreasing));
+    assert!(!IconName::CirclePause.can_morph_into(IconName::CircleAlert));
+    assert!(!IconName::Navigation.can_morph_into(IconName::Navigation2));
+
+    let rule = LUCIDE_CSS
+        .split("transition-property:")
+        .nth(1)
+        .and_then(|rest| rest.split(';').next())
+        .unwrap();
+    let properties: Vec<&str> = rule.split(',').map(str::trim).collect();
+    assert_eq!(
+        properties,
+        ["d", "cx", "cy", "r", "rx", "ry", "x", "y", "width", "height"]
+    );
+}
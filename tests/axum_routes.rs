@@ -0,0 +1,158 @@
+//! `icon_routes` served by a local in-process server and queried over plain
+//! HTTP/1.1.
+//!
+//! Run with `cargo test --features axum`.
+#![cfg(feature = "axum")]
+
+use leptos_lucide_rs::*;
+use std::net::SocketAddr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+struct HttpResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl HttpResponse {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+async fn spawn_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, icon_routes::<()>()).await.unwrap();
+    });
+    addr
+}
+
+async fn get(addr: SocketAddr, path: &str, extra_headers: &[(&str, &str)]) -> HttpResponse {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    let mut request = format!("GET {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n");
+    for (name, value) in extra_headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut raw = String::new();
+    stream.read_to_string(&mut raw).await.unwrap();
+
+    let (head, body) = raw.split_once("\r\n\r\n").unwrap();
+    let mut lines = head.lines();
+    let status = lines.next().unwrap().split(' ').nth(1).unwrap().parse().unwrap();
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.to_string(), value.trim().to_string()))
+        .collect();
+
+    HttpResponse {
+        status,
+        headers,
+        body: body.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn serves_icon_svg() {
+    let addr = spawn_server().await;
+    let response = get(addr, "/icons/house.svg", &[]).await;
+
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.header("content-type"),
+        Some("image/svg+xml; charset=utf-8")
+    );
+    assert_eq!(
+        response.header("cache-control"),
+        Some("public, max-age=31536000, immutable")
+    );
+    assert_eq!(
+        response.body,
+        render_icon_svg(IconName::House, &IconConfig::default())
+    );
+}
+
+#[tokio::test]
+async fn applies_query_parameters() {
+    let addr = spawn_server().await;
+    let response = get(
+        addr,
+        "/icons/heart.svg?size=48&color=%23e11d48&stroke_width=1.5",
+        &[],
+    )
+    .await;
+
+    let config = IconConfig::new()
+        .size("48")
+        .stroke("#e11d48")
+        .stroke_width("1.5");
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, render_icon_svg(IconName::Heart, &config));
+}
+
+#[tokio::test]
+async fn etag_is_strong_and_stable() {
+    let addr = spawn_server().await;
+    let first = get(addr, "/icons/star.svg", &[]).await;
+    let second = get(addr, "/icons/star.svg", &[]).await;
+    let other = get(addr, "/icons/star.svg?size=32", &[]).await;
+
+    let etag = first.header("etag").unwrap();
+    assert!(etag.starts_with('"') && etag.ends_with('"'), "{etag}");
+    assert_eq!(second.header("etag"), Some(etag));
+    assert_ne!(other.header("etag"), Some(etag));
+}
+
+#[tokio::test]
+async fn matching_etag_returns_not_modified() {
+    let addr = spawn_server().await;
+    let first = get(addr, "/icons/star.svg", &[]).await;
+    let etag = first.header("etag").unwrap();
+
+    let revalidated = get(addr, "/icons/star.svg", &[("If-None-Match", etag)]).await;
+    assert_eq!(revalidated.status, 304);
+    assert_eq!(revalidated.header("etag"), Some(etag));
+    assert!(revalidated.body.is_empty());
+
+    let stale = get(addr, "/icons/star.svg", &[("If-None-Match", "\"0\"")]).await;
+    assert_eq!(stale.status, 200);
+}
+
+#[tokio::test]
+async fn renamed_icons_are_served() {
+    let addr = spawn_server().await;
+    let response = get(addr, "/icons/home.svg", &[]).await;
+
+    assert_eq!(response.status, 200);
+    assert!(response.body.contains(r#"data-lucide="house""#));
+}
+
+#[tokio::test]
+async fn unknown_icon_suggests_similar_names() {
+    let addr = spawn_server().await;
+    let response = get(addr, "/icons/hose.svg", &[]).await;
+
+    assert_eq!(response.status, 404);
+    assert!(response.body.starts_with("unknown icon `hose`"), "{}", response.body);
+    assert!(response.body.contains("house"), "{}", response.body);
+}
+
+#[tokio::test]
+async fn rejects_other_extensions_and_bad_sizes() {
+    let addr = spawn_server().await;
+
+    assert_eq!(get(addr, "/icons/house.png", &[]).await.status, 404);
+    assert_eq!(get(addr, "/icons/house.svg?size=big", &[]).await.status, 400);
+    assert_eq!(
+        get(addr, "/icons/house.svg?stroke_width=-1", &[]).await.status,
+        400
+    );
+}
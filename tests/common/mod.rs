@@ -0,0 +1,24 @@
+//! Helpers shared by the server rendering tests.
+
+// Each test crate uses only some of these
+#![allow(dead_code)]
+
+use leptos::prelude::*;
+
+/// Run `render` under a fresh reactive owner
+pub fn ssr<F: FnOnce() -> String>(render: F) -> String {
+    Owner::new().with(render)
+}
+
+/// Markup between an `<svg ...>` and its `</svg>`
+pub fn inner(svg: &str) -> &str {
+    let start = svg.find('>').unwrap() + 1;
+    let end = svg.rfind("</svg>").unwrap();
+    &svg[start..end]
+}
+
+/// `html` without the `<!>` markers, which mark where a list of children ends
+/// for hydration and which the string renderer doesn't emit
+pub fn without_markers(html: &str) -> String {
+    html.replace("<!>", "")
+}
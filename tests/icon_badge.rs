@@ -0,0 +1,152 @@
+//! `IconBadge` server rendering.
+//!
+//! Run with `cargo test --features ssr`.
+#![cfg(feature = "ssr")]
+
+mod common;
+
+use common::ssr;
+use hydration_context::SsrSharedContext;
+use leptos::prelude::*;
+use leptos_lucide_rs::*;
+use std::sync::Arc;
+
+/// `id` of every mask in `html`
+fn mask_ids(html: &str) -> Vec<&str> {
+    html.split(r#"<mask id=""#)
+        .skip(1)
+        .map(|rest| &rest[..rest.find('"').unwrap()])
+        .collect()
+}
+
+#[test]
+fn shows_counts_and_cuts_a_notch() {
+    let html = ssr(|| view! { <IconBadge icon=IconName::Mail count=3/> }.to_html());
+
+    assert!(html.contains(r#"data-lucide="mail""#), "{html}");
+    assert!(html.contains(r#"style="overflow: visible;""#), "{html}");
+    // The icon's shapes are masked and the mask clears the badge's corner
+    let [id] = mask_ids(&html)[..] else {
+        panic!("expected one mask: {html}");
+    };
+    assert!(
+        html.contains(&format!(r#"<g mask="url(#{id})"><path"#)),
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"<rect x="12.5" y="-3.5" width="15" height="15" rx="7.5" fill="black">"#),
+        "{html}"
+    );
+    assert!(html.contains(r##"fill="#ef4444""##), "{html}");
+    assert!(html.contains(r#"fill="white""#), "{html}");
+    assert!(html.contains(">3</text>"), "{html}");
+}
+
+#[test]
+fn badges_in_the_same_corner_get_their_own_masks() {
+    let html = ssr(|| {
+        view! {
+            <IconBadge icon=IconName::Mail count=3/>
+            <IconBadge icon=IconName::Bell count=5/>
+        }
+        .to_html()
+    });
+
+    let ids = mask_ids(&html);
+    assert_eq!(ids.len(), 2, "{html}");
+    assert_ne!(ids[0], ids[1], "{html}");
+    for id in ids {
+        assert_eq!(html.matches(&format!("url(#{id})")).count(), 1, "{html}");
+    }
+}
+
+#[test]
+fn takes_mask_ids_from_the_hydration_context() {
+    // What the server renders and what the client hydrates both take ids
+    // from a fresh context, in the same order
+    let render = || {
+        let owner = Owner::new_root(Some(Arc::new(SsrSharedContext::new())));
+        owner.with(|| {
+            view! {
+                <IconBadge icon=IconName::Mail count=3/>
+                <IconBadge icon=IconName::Bell dot=true/>
+            }
+            .to_html()
+        })
+    };
+    let (first, second) = (render(), render());
+
+    let ids = mask_ids(&first);
+    assert_eq!(ids.len(), 2, "{first}");
+    assert_ne!(ids[0], ids[1], "{first}");
+    assert!(!ids[0].starts_with("lucide-badge-c"), "{first}");
+    assert_eq!(ids, mask_ids(&second));
+}
+
+#[test]
+fn sets_the_text_color() {
+    let html = ssr(|| {
+        view! { <IconBadge icon=IconName::Mail count=3 color="#facc15" text_color="#111827"/> }
+            .to_html()
+    });
+
+    assert!(html.contains(r##"fill="#facc15""##), "{html}");
+    assert!(html.contains(r##"fill="#111827""##), "{html}");
+    assert!(!html.contains(r#"fill="white" font-size"#), "{html}");
+}
+
+#[test]
+fn caps_counts_at_max() {
+    let html = ssr(|| view! { <IconBadge icon=IconName::Inbox count=120/> }.to_html());
+    assert!(html.contains(">99+</text>"), "{html}");
+
+    let html = ssr(|| view! { <IconBadge icon=IconName::Inbox count=12 max=9/> }.to_html());
+    assert!(html.contains(">9+</text>"), "{html}");
+    // Two characters widen the badge and its notch
+    assert!(html.contains(r#"width="19" height="15""#), "{html}");
+}
+
+#[test]
+fn hides_the_badge_for_zero() {
+    let html = ssr(|| view! { <IconBadge icon=IconName::Bell count=0/> }.to_html());
+
+    assert!(!html.contains("<mask"), "{html}");
+    assert!(!html.contains(r#"mask="#), "{html}");
+    assert!(!html.contains("lucide-badge"), "{html}");
+}
+
+#[test]
+fn places_dots_in_any_corner() {
+    for &position in BadgePosition::ALL {
+        let html = ssr(|| {
+            view! {
+                <IconBadge icon=IconName::Bell dot=true position=position color="#22c55e"/>
+            }
+            .to_html()
+        });
+        let [id] = mask_ids(&html)[..] else {
+            panic!("expected one mask: {html}");
+        };
+
+        assert!(html.contains(&format!(r#"mask="url(#{id})""#)), "{html}");
+        assert!(html.contains(r#"width="7" height="7" rx="3.5""#), "{html}");
+        assert!(html.contains(r##"fill="#22c55e""##), "{html}");
+        assert!(!html.contains("<text"), "{html}");
+    }
+}
+
+#[test]
+fn follows_a_signal_count() {
+    let owner = Owner::new();
+    owner.with(|| {
+        let (count, set_count) = signal(0);
+        let badge = view! { <IconBadge icon=IconName::Bell count=count/> };
+
+        // The badge built while the count was zero shows the count it has by
+        // the time it renders
+        set_count.set(7);
+        let html = badge.to_html();
+        assert!(html.contains(">7</text>"), "{html}");
+        assert!(html.contains(r#"<g mask="url(#"#), "{html}");
+    });
+}
@@ -0,0 +1,56 @@
+//! `IconButton` server rendering.
+//!
+//! Run with `cargo test --features ssr`.
+#![cfg(feature = "ssr")]
+
+mod common;
+
+use common::ssr;
+use leptos::prelude::*;
+use leptos_lucide_rs::*;
+
+#[test]
+fn renders_a_labelled_button() {
+    let html = ssr(|| {
+        view! { <IconButton icon=IconName::Trash2 label="Delete" class="danger" size="20"/> }
+            .to_html()
+    });
+
+    assert!(html.starts_with(r#"<button type="button""#), "{html}");
+    assert!(html.contains(r#"aria-label="Delete""#), "{html}");
+    assert!(html.contains(r#"class="lucide-button danger""#), "{html}");
+    assert!(!html.contains("aria-busy"), "{html}");
+    assert!(!html.contains("disabled"), "{html}");
+    assert!(html.contains(r#"data-lucide="trash-2""#), "{html}");
+    assert!(html.contains(r#"width="20""#), "{html}");
+}
+
+#[test]
+fn shows_a_spinner_while_loading() {
+    let html =
+        ssr(|| view! { <IconButton icon=IconName::Save label="Save" loading=true/> }.to_html());
+
+    assert!(html.contains(r#"aria-busy="true""#), "{html}");
+    assert!(html.contains(r#"data-lucide="loader-circle""#), "{html}");
+    assert!(
+        html.contains(r#"class="lucide-icon lucide-spin""#),
+        "{html}"
+    );
+    assert!(!html.contains(r#"data-lucide="save""#), "{html}");
+}
+
+#[test]
+fn can_be_disabled() {
+    let html =
+        ssr(|| view! { <IconButton icon=IconName::Send label="Send" disabled=true/> }.to_html());
+
+    assert!(html.contains(" disabled"), "{html}");
+}
+
+#[test]
+fn icon_button_wrapper_is_not_a_submit_button() {
+    let html =
+        ssr(|| view! { <Icon icon=|| view! { <Plus/> } wrapper="button".to_string()/> }.to_html());
+
+    assert!(html.starts_with(r#"<button type="button""#), "{html}");
+}
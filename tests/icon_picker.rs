@@ -0,0 +1,157 @@
+//! `IconPicker` keyboard behavior, search and virtualized grid.
+//!
+//! Run with `cargo test --features picker`.
+#![cfg(feature = "picker")]
+
+use leptos_lucide_rs::{
+    filter_icons, picker_key_action, picker_visible_range, IconCategory, IconName, PickerFocus,
+    PickerKeyAction,
+};
+
+const LEN: usize = 20;
+const COLUMNS: usize = 8;
+
+fn grid(key: &str, current: usize) -> Option<PickerKeyAction> {
+    picker_key_action(PickerFocus::Grid, key, current, LEN, COLUMNS)
+}
+
+fn search(key: &str, current: usize) -> Option<PickerKeyAction> {
+    picker_key_action(PickerFocus::Search, key, current, LEN, COLUMNS)
+}
+
+#[test]
+fn grid_moves_in_two_dimensions() {
+    use PickerKeyAction::Highlight;
+
+    assert_eq!(grid("ArrowRight", 3), Some(Highlight(4)));
+    assert_eq!(grid("ArrowLeft", 3), Some(Highlight(2)));
+    assert_eq!(grid("ArrowDown", 3), Some(Highlight(11)));
+    assert_eq!(grid("ArrowUp", 11), Some(Highlight(3)));
+    assert_eq!(grid("Home", 11), Some(Highlight(0)));
+    assert_eq!(grid("End", 3), Some(Highlight(LEN - 1)));
+}
+
+#[test]
+fn grid_stops_at_the_edges() {
+    use PickerKeyAction::Highlight;
+
+    assert_eq!(grid("ArrowLeft", 0), Some(Highlight(0)));
+    assert_eq!(grid("ArrowUp", 3), Some(Highlight(0)));
+    assert_eq!(grid("ArrowRight", LEN - 1), Some(Highlight(LEN - 1)));
+    assert_eq!(grid("ArrowDown", 15), Some(Highlight(LEN - 1)));
+}
+
+#[test]
+fn search_box_leaves_text_editing_keys_alone() {
+    for key in ["ArrowLeft", "ArrowRight", "Home", "End", "a", " "] {
+        assert_eq!(search(key, 3), None, "{key}");
+    }
+    assert_eq!(search("ArrowDown", 3), Some(PickerKeyAction::Highlight(11)));
+    assert_eq!(search("ArrowUp", 11), Some(PickerKeyAction::Highlight(3)));
+}
+
+#[test]
+fn enter_selects_the_highlighted_icon() {
+    assert_eq!(grid("Enter", 5), Some(PickerKeyAction::Select(5)));
+    assert_eq!(search("Enter", 5), Some(PickerKeyAction::Select(5)));
+    // A highlight left over from a longer result list is clamped
+    assert_eq!(grid("Enter", 50), Some(PickerKeyAction::Select(LEN - 1)));
+}
+
+#[test]
+fn unhandled_keys_and_empty_results_do_nothing() {
+    assert_eq!(grid("Tab", 3), None);
+    assert_eq!(
+        picker_key_action(PickerFocus::Grid, "ArrowDown", 0, 0, COLUMNS),
+        None
+    );
+    assert_eq!(
+        picker_key_action(PickerFocus::Search, "Enter", 0, 0, COLUMNS),
+        None
+    );
+}
+
+#[test]
+fn empty_query_lists_every_icon() {
+    assert_eq!(filter_icons("", None), IconName::ALL);
+    assert_eq!(filter_icons("   ", None), IconName::ALL);
+}
+
+#[test]
+fn search_matches_every_term() {
+    let icons = filter_icons("Arrow  LEFT", None);
+
+    assert!(icons.contains(&IconName::ArrowLeft), "{icons:?}");
+    assert!(icons.contains(&IconName::CircleArrowLeft), "{icons:?}");
+    assert!(!icons.contains(&IconName::ArrowRight), "{icons:?}");
+    assert!(icons
+        .iter()
+        .all(|icon| icon.as_str().contains("arrow") && icon.as_str().contains("left")));
+    assert_eq!(filter_icons("no-such-icon", None), []);
+}
+
+#[test]
+fn search_matches_former_names() {
+    // Neither name contains the term; both were renamed to it
+    assert!(filter_icons("home", None).contains(&IconName::House));
+    assert!(filter_icons("edit", None).contains(&IconName::Pencil));
+}
+
+#[test]
+fn category_filter_restricts_the_results() {
+    let arrows = filter_icons("", Some(IconCategory::Arrows));
+    assert!(arrows.contains(&IconName::ArrowLeft), "{arrows:?}");
+    assert!(arrows.len() < IconName::ALL.len());
+    assert!(arrows
+        .iter()
+        .all(|icon| icon.category() == IconCategory::Arrows));
+
+    // Searching within a category keeps only the matches in it
+    let left = filter_icons("left", Some(IconCategory::Arrows));
+    assert!(left.contains(&IconName::ArrowLeft), "{left:?}");
+    assert!(left.iter().all(|icon| arrows.contains(icon)));
+    assert!(left.len() < filter_icons("left", None).len());
+}
+
+// 197 icons in rows of 8 make 24 full rows and a last row of 5, shown 6 rows
+// at a time in 40px cells
+const ICONS: usize = 197;
+const CELL: usize = 40;
+const VISIBLE_ROWS: usize = 6;
+
+fn window(scroll_top: usize) -> std::ops::Range<usize> {
+    picker_visible_range(scroll_top, CELL, VISIBLE_ROWS, COLUMNS, ICONS)
+}
+
+#[test]
+fn mounts_the_first_rows_at_the_top() {
+    // The visible rows and two overscan rows below them
+    assert_eq!(window(0), 0..10 * COLUMNS);
+    assert_eq!(window(CELL - 1), 0..10 * COLUMNS);
+}
+
+#[test]
+fn mounts_overscan_rows_on_both_sides() {
+    // Scrolled to row 10, rows 8 to 17 are mounted
+    assert_eq!(window(10 * CELL), 8 * COLUMNS..18 * COLUMNS);
+}
+
+#[test]
+fn mounts_the_last_partial_row() {
+    let bottom = (25 - VISIBLE_ROWS) * CELL;
+    assert_eq!(window(bottom), 15 * COLUMNS..ICONS);
+}
+
+#[test]
+fn clamps_overscroll_to_the_last_rows() {
+    assert_eq!(window(100_000), 15 * COLUMNS..ICONS);
+    // A scroll position left over from a longer list
+    assert_eq!(
+        picker_visible_range(20 * CELL, CELL, VISIBLE_ROWS, COLUMNS, 5),
+        0..5
+    );
+    assert_eq!(
+        picker_visible_range(20 * CELL, CELL, VISIBLE_ROWS, COLUMNS, 0),
+        0..0
+    );
+}
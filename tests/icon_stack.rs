@@ -0,0 +1,80 @@
+//! `IconStack` and `IconLayer` server rendering.
+//!
+//! Run with `cargo test --features ssr`.
+#![cfg(feature = "ssr")]
+
+mod common;
+
+use common::{inner, ssr, without_markers};
+use leptos::prelude::*;
+use leptos_lucide_rs::*;
+
+#[test]
+fn lays_out_layers_in_one_cell() {
+    let html = ssr(|| {
+        view! {
+            <IconStack size="48" class="badge">
+                <IconLayer icon=IconName::Square color="#e5e7eb"/>
+                <IconLayer icon=IconName::Heart scale=0.5 color="#ef4444"/>
+            </IconStack>
+        }
+        .to_html()
+    });
+
+    assert!(
+        html.starts_with(r#"<span class="lucide-stack badge" style="display: inline-grid; grid-template: 100% / 100%; width: 48px; height: 48px;"#),
+        "{html}"
+    );
+    assert_eq!(html.matches("<svg").count(), 2, "{html}");
+    assert_eq!(
+        html.matches(r#"width="100%" height="100%""#).count(),
+        2,
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"style="grid-area: 1 / 1; color: #e5e7eb;""#),
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"style="grid-area: 1 / 1; transform: scale(0.5); color: #ef4444;""#),
+        "{html}"
+    );
+    // Drawn in order, so the heart is on top
+    assert!(html.find(r#"data-lucide="square""#) < html.find(r#"data-lucide="heart""#));
+}
+
+#[test]
+fn offsets_are_fractions_of_the_stack() {
+    let html = ssr(|| {
+        view! {
+            <IconStack>
+                <IconLayer icon=IconName::Ban offset_x=0.1 offset_y=-0.25 scale=0.4/>
+            </IconStack>
+        }
+        .to_html()
+    });
+
+    assert!(html.contains("width: 24px; height: 24px;"), "{html}");
+    assert!(
+        html.contains("transform: translate(10%, -25%) scale(0.4)"),
+        "{html}"
+    );
+}
+
+#[test]
+fn layers_render_the_icon_shapes() {
+    for icon in [IconName::House, IconName::Camera, IconName::Ban] {
+        let html = ssr(|| {
+            view! {
+                <IconStack>
+                    <IconLayer icon=icon stroke_width="1.5"/>
+                </IconStack>
+            }
+            .to_html()
+        });
+        let expected = render_icon_svg(icon, &IconConfig::default());
+        let svg = &html[html.find("<svg").unwrap()..html.rfind("</span>").unwrap()];
+        assert!(svg.contains(r#"stroke-width="1.5""#), "{svg}");
+        assert_eq!(without_markers(inner(svg)), inner(&expected), "{icon}");
+    }
+}
@@ -0,0 +1,136 @@
+//! `IconSwitch` server rendering.
+//!
+//! Run with `cargo test --features ssr`.
+#![cfg(feature = "ssr")]
+
+mod common;
+
+use common::{ssr, without_markers};
+use leptos::prelude::*;
+use leptos_lucide_rs::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Sort {
+    Ascending,
+    Descending,
+}
+
+fn sort_icon(sort: &Sort) -> (IconName, IconConfig) {
+    match sort {
+        Sort::Ascending => (IconName::ArrowUpNarrowWide, IconConfig::new().class("asc")),
+        Sort::Descending => (
+            IconName::ArrowDownWideNarrow,
+            IconConfig::new().class("desc").size("20"),
+        ),
+    }
+}
+
+#[test]
+fn renders_the_mapped_icon_and_config() {
+    let html = ssr(|| view! { <IconSwitch value=Sort::Descending map=sort_icon/> }.to_html());
+
+    assert!(
+        html.contains(r#"data-lucide="arrow-down-wide-narrow""#),
+        "{html}"
+    );
+    assert!(html.contains(r#"class="lucide-icon desc""#), "{html}");
+    assert!(html.contains(r#"width="20" height="20""#), "{html}");
+}
+
+#[test]
+fn renders_the_same_markup_as_the_string_renderer() {
+    for sort in [Sort::Ascending, Sort::Descending] {
+        let html = ssr(|| view! { <IconSwitch value=sort map=sort_icon/> }.to_html());
+        let (icon, config) = sort_icon(&sort);
+        let expected = render_icon_svg(icon, &config);
+        assert_eq!(without_markers(&html), expected);
+    }
+}
+
+#[test]
+fn draws_filled_shapes_first() {
+    let map = |filled: &bool| (IconName::Mail, IconConfig::new().filled(*filled));
+    for filled in [false, true] {
+        let html = ssr(|| view! { <IconSwitch value=filled map=map/> }.to_html());
+        let (icon, config) = map(&filled);
+        assert_eq!(without_markers(&html), render_icon_svg(icon, &config));
+    }
+}
+
+#[tokio::test]
+async fn follows_a_signal() {
+    let _ = any_spawner::Executor::init_tokio();
+    let owner = Owner::new();
+    let (set_liked, rendered) = owner.with(|| {
+        let (liked, set_liked) = signal(false);
+        let map = |liked: &bool| {
+            if *liked {
+                (IconName::Heart, IconConfig::new().fill("currentColor"))
+            } else {
+                (IconName::Heart, IconConfig::new())
+            }
+        };
+
+        // Renders now and again whenever the switch's value changes
+        let rendered = RenderEffect::new_isomorphic(move |_| {
+            view! { <IconSwitch value=liked map=map/> }.to_html()
+        });
+        (set_liked, rendered)
+    });
+
+    let html = rendered.with_value_mut(|html| html.clone()).unwrap();
+    assert!(html.contains(r#"fill="none""#), "{html}");
+
+    set_liked.set(true);
+    any_spawner::Executor::tick().await;
+    let html = rendered.with_value_mut(|html| html.clone()).unwrap();
+    assert!(html.contains(r#"data-lucide="heart""#), "{html}");
+    assert!(html.contains(r#"fill="currentColor""#), "{html}");
+    assert!(!html.contains(r#"fill="none""#), "{html}");
+}
+
+#[test]
+fn same_elements_compares_tags_in_order() {
+    // One path each
+    assert!(IconName::Minus.same_elements(IconName::Slash));
+    assert!(IconName::Heart.same_elements(IconName::Minus));
+    // One path against a circle, or against two paths
+    assert!(!IconName::Minus.same_elements(IconName::Circle));
+    assert!(!IconName::Minus.same_elements(IconName::Plus));
+    // A polyline and three lines against two lines
+    assert!(!IconName::Sword.same_elements(IconName::Equal));
+}
+
+#[test]
+fn layout_changes_only_with_the_elements() {
+    let owner = Owner::new();
+    owner.with(|| {
+        let (icon, set_icon) = signal(IconName::Minus);
+        let layout = icon_layout(icon.into());
+        // Stands in for the switch's children, which re-render with the layout
+        let renders = StoredValue::new(0);
+        let children = Memo::new(move |_| {
+            renders.update_value(|renders| *renders += 1);
+            layout.get()
+        });
+
+        assert_eq!(children.get(), IconName::Minus);
+        assert_eq!(renders.get_value(), 1);
+
+        // Same single path: the mounted children are kept
+        set_icon.set(IconName::Slash);
+        assert_eq!(layout.get(), IconName::Minus);
+        assert_eq!(children.get(), IconName::Minus);
+        assert_eq!(renders.get_value(), 1);
+
+        // A circle needs new children
+        set_icon.set(IconName::Circle);
+        assert_eq!(children.get(), IconName::Circle);
+        assert_eq!(renders.get_value(), 2);
+
+        // Another circle keeps them again
+        set_icon.set(IconName::CircleSmall);
+        assert_eq!(children.get(), IconName::Circle);
+        assert_eq!(renders.get_value(), 2);
+    });
+}
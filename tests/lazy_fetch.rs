@@ -0,0 +1,263 @@
+//! Lazy icon fetching against a local stand-in icon server.
+//!
+//! The server records how often each path is requested, so the tests can tell
+//! cache hits from network fetches. It runs on its own thread so it outlives
+//! the per-test runtimes.
+//!
+//! Run with `cargo test --features lazy`; rendering `DynamicIcon` also needs
+//! the `ssr` feature.
+#![cfg(feature = "lazy")]
+
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use leptos_lucide_rs::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+fn hits() -> &'static Mutex<HashMap<String, usize>> {
+    static HITS: OnceLock<Mutex<HashMap<String, usize>>> = OnceLock::new();
+    HITS.get_or_init(Default::default)
+}
+
+fn hit_count(icon: IconName) -> usize {
+    let path = format!("{}.svg", icon.as_str());
+    hits().lock().unwrap().get(&path).copied().unwrap_or(0)
+}
+
+async fn stand_in(Path(file): Path<String>) -> Result<String, StatusCode> {
+    *hits().lock().unwrap().entry(file.clone()).or_default() += 1;
+    let name = file.strip_suffix(".svg").ok_or(StatusCode::NOT_FOUND)?;
+    match name {
+        "house" | "heart" | "star" | "search" | "sun" => {
+            let icon = IconName::from_name(name).unwrap();
+            Ok(render_icon_svg(icon, &IconConfig::default()))
+        }
+        // Slow enough for concurrent requests to overlap
+        "mail" => {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok(render_icon_svg(IconName::Mail, &IconConfig::default()))
+        }
+        "bell" => Ok(concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" onload="alert(1)">"#,
+            r#"<script>alert(1)</script>"#,
+            r#"<path d="M1 1h2" onclick="alert(1)" style="x"/>"#,
+            r#"<foreignObject><div/></foreignObject>"#,
+            r#"<circle cx="12" cy="12" r="3"/>"#,
+            "</svg>",
+        )
+        .to_string()),
+        "bold" => Ok("<html><body/></html>".to_string()),
+        _ => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+/// Start the stand-in server once and point lazy icons at it
+fn serve() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    STARTED.get_or_init(|| {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        listener.set_nonblocking(true).unwrap();
+
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().unwrap();
+            runtime.block_on(async move {
+                let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+                let app = Router::new().route("/lucide/{file}", get(stand_in));
+                axum::serve(listener, app).await.unwrap();
+            });
+        });
+
+        set_icon_base_url(format!("http://{addr}/lucide/"));
+        let _ = any_spawner::Executor::init_tokio();
+    });
+}
+
+#[tokio::test]
+async fn fetches_the_icon_nodes() {
+    serve();
+    assert!(icon_url(IconName::House).ends_with("/lucide/house.svg"));
+
+    let nodes = fetch_icon(IconName::House).await.unwrap();
+    let shapes = |nodes: &[IconNode]| {
+        nodes
+            .iter()
+            .map(|node| (node.tag, node.attrs.to_vec()))
+            .collect::<Vec<_>>()
+    };
+    assert!(!nodes.is_empty());
+    assert_eq!(shapes(nodes), shapes(IconName::House.nodes()));
+}
+
+#[tokio::test]
+async fn keeps_only_shapes_and_their_geometry() {
+    serve();
+    let nodes = fetch_icon(IconName::Bell).await.unwrap();
+
+    let tags: Vec<&str> = nodes.iter().map(|node| node.tag).collect();
+    assert_eq!(tags, ["path", "circle"]);
+    assert_eq!(nodes[0].attrs, [("d", "M1 1h2")]);
+    assert_eq!(nodes[1].attrs, [("cx", "12"), ("cy", "12"), ("r", "3")]);
+}
+
+#[tokio::test]
+async fn non_svg_responses_are_errors() {
+    serve();
+    let result = fetch_icon(IconName::Bold).await;
+
+    assert!(
+        matches!(result, Err(LazyIconError::Invalid(_))),
+        "{result:?}"
+    );
+    assert_eq!(cached_icon(IconName::Bold), None);
+}
+
+#[tokio::test]
+async fn concurrent_fetches_share_one_request() {
+    serve();
+    let icon = IconName::Mail;
+
+    let (first, second) = tokio::join!(fetch_icon(icon), fetch_icon(icon));
+
+    assert_eq!(first.unwrap(), second.unwrap());
+    assert_eq!(hit_count(icon), 1);
+}
+
+#[tokio::test]
+async fn second_fetch_is_served_from_cache() {
+    serve();
+    let first = fetch_icon(IconName::Heart).await.unwrap();
+    let requests = hit_count(IconName::Heart);
+    let second = fetch_icon(IconName::Heart).await.unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(hit_count(IconName::Heart), requests);
+    assert_eq!(cached_icon(IconName::Heart), Some(first));
+}
+
+#[tokio::test]
+async fn missing_icons_are_errors_and_not_cached() {
+    serve();
+    let icon = IconName::User;
+
+    assert_eq!(fetch_icon(icon).await, Err(LazyIconError::Status(404)));
+    assert_eq!(fetch_icon(icon).await, Err(LazyIconError::Status(404)));
+    assert_eq!(hit_count(icon), 2);
+    assert_eq!(cached_icon(icon), None);
+}
+
+#[tokio::test]
+async fn preload_fills_the_cache() {
+    serve();
+    preload_icons(&[IconName::Star, IconName::Search]);
+
+    for _ in 0..200 {
+        if cached_icon(IconName::Star).is_some() && cached_icon(IconName::Search).is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    assert!(cached_icon(IconName::Star).is_some());
+    assert!(cached_icon(IconName::Search).is_some());
+
+    let requests = hit_count(IconName::Star);
+    preload_icons(&[IconName::Star]);
+    fetch_icon(IconName::Star).await.unwrap();
+    assert_eq!(hit_count(IconName::Star), requests);
+}
+
+#[cfg(feature = "ssr")]
+mod dynamic_icon {
+    use super::*;
+    use futures::StreamExt;
+    use hydration_context::SsrSharedContext;
+    use leptos::prelude::*;
+    use std::future::Future;
+    use tokio::task::LocalSet;
+
+    /// Run `test` where fetches can be spawned on the current thread, as they
+    /// are outside hydration
+    async fn local(test: impl Future<Output = ()>) {
+        serve();
+        LocalSet::new().run_until(test).await;
+    }
+
+    /// Render `view` once its `Suspense` has resolved
+    async fn render_resolved<V: IntoView + 'static>(view: impl FnOnce() -> V) -> String {
+        let owner = Owner::new();
+        let html = owner.with(|| view().into_view().to_html_stream_in_order());
+        html.collect::<String>().await
+    }
+
+    #[tokio::test]
+    async fn shows_a_placeholder_then_the_icon() {
+        local(async {
+            let view = || view! { <DynamicIcon icon=IconName::Sun size="20"/> };
+
+            let placeholder = Owner::new().with(|| view().to_html());
+            assert!(
+                placeholder.contains(r#"width="20" height="20""#),
+                "{placeholder}"
+            );
+            assert!(placeholder.contains("lucide-loading"), "{placeholder}");
+            assert!(!placeholder.contains("<circle"), "{placeholder}");
+
+            let html = render_resolved(view).await;
+            assert!(html.contains(r#"data-lucide="sun""#), "{html}");
+            assert!(!html.contains("lucide-loading"), "{html}");
+            assert!(html.contains("<circle"), "{html}");
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn falls_back_and_reports_errors() {
+        local(async {
+            let errors = Arc::new(Mutex::new(Vec::new()));
+            let reported = errors.clone();
+
+            let html = render_resolved(move || {
+                view! {
+                    <DynamicIcon
+                        icon=IconName::Calendar
+                        fallback=|| view! { <span>"no icon"</span> }
+                        on_error=move |err: LazyIconError| reported.lock().unwrap().push(err)
+                    />
+                }
+            })
+            .await;
+
+            assert!(html.contains("<span>no icon</span>"), "{html}");
+            assert!(!html.contains("<svg"), "{html}");
+            assert_eq!(*errors.lock().unwrap(), [LazyIconError::Status(404)]);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn hydrated_pages_are_fetched_in_the_browser() {
+        serve();
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let reported = errors.clone();
+
+        let owner = Owner::new_root(Some(Arc::new(SsrSharedContext::new())));
+        let html = owner.with(|| {
+            view! {
+                <DynamicIcon
+                    icon=IconName::Moon
+                    on_error=move |err: LazyIconError| reported.lock().unwrap().push(err)
+                />
+            }
+            .to_html()
+        });
+        any_spawner::Executor::tick().await;
+
+        assert!(html.contains("lucide-loading"), "{html}");
+        assert_eq!(hit_count(IconName::Moon), 0);
+        assert!(errors.lock().unwrap().is_empty());
+    }
+}
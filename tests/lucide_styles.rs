@@ -0,0 +1,82 @@
+//! `LucideStyles` rendered on the server through `leptos_meta`.
+//!
+//! Run with `cargo test --features ssr,meta`.
+#![cfg(all(feature = "ssr", feature = "meta"))]
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_lucide_rs::*;
+use leptos_meta::ServerMetaContext;
+
+/// Render `app` and return the `<head>` `leptos_meta` fills in for it
+async fn head<V: IntoView + 'static>(app: impl FnOnce() -> V) -> String {
+    let _ = any_spawner::Executor::init_tokio();
+    let owner = Owner::new();
+    let (meta, output) = ServerMetaContext::new();
+    let body = owner.with(|| {
+        provide_context(meta);
+        leptos_meta::provide_meta_context();
+        provide_lucide_styles();
+        app().into_view().to_html()
+    });
+
+    let page = format!("<html><head></head><body>{body}</body></html>");
+    let html: String = output
+        .inject_meta_context(futures::stream::once(async move { page }).boxed())
+        .await
+        .collect()
+        .await;
+    let start = html.find("<head>").unwrap() + "<head>".len();
+    let end = html.find("</head>").unwrap();
+    html[start..end].to_string()
+}
+
+#[tokio::test]
+async fn injects_the_stylesheet() {
+    let head = head(|| view! { <LucideStyles/> }).await;
+
+    assert!(
+        head.starts_with(r#"<style id="leptos-lucide-styles">"#),
+        "{head}"
+    );
+    assert!(head.contains("@keyframes lucide-spin"), "{head}");
+    assert!(head.contains("prefers-reduced-motion: reduce"), "{head}");
+}
+
+#[tokio::test]
+async fn injects_only_once() {
+    let head = head(|| {
+        view! {
+            <LucideStyles/>
+            <div>
+                <LucideStyles/>
+            </div>
+            <LucideStyles/>
+        }
+    })
+    .await;
+
+    assert_eq!(head.matches("<style").count(), 1, "{head}");
+}
+
+#[tokio::test]
+async fn hands_the_stylesheet_over_when_the_first_unmounts() {
+    let head = head(|| {
+        let first = Owner::current().unwrap().child();
+        let _unmounted = first.with(|| view! { <LucideStyles/> });
+        let second = view! { <LucideStyles/> };
+        first.cleanup();
+        second
+    })
+    .await;
+
+    assert_eq!(head.matches("<style").count(), 1, "{head}");
+    assert!(head.contains("@keyframes lucide-spin"), "{head}");
+}
+
+#[tokio::test]
+async fn applies_the_nonce() {
+    let head = head(|| view! { <LucideStyles nonce="r4nd0m"/> }).await;
+
+    assert!(head.contains(r#"nonce="r4nd0m""#), "{head}");
+}
@@ -0,0 +1,113 @@
+//! Golden-image tests for `render_png`.
+//!
+//! Each case is rendered and compared pixel by pixel against a PNG in
+//! `tests/golden`, allowing a small per-channel difference for anti-aliasing
+//! changes between rasterizer releases. Mismatching renders are written next
+//! to the test target directory for inspection.
+//!
+//! Run with `cargo test --features raster`. After an intentional change,
+//! regenerate the goldens with `UPDATE_GOLDENS=1 cargo test --features raster`.
+#![cfg(feature = "raster")]
+
+use leptos_lucide_rs::*;
+use resvg::tiny_skia::Pixmap;
+use std::path::{Path, PathBuf};
+
+/// Largest per-channel difference treated as equal
+const TOLERANCE: u8 = 8;
+
+fn golden_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.png"))
+}
+
+fn assert_golden(name: &str, png: &[u8]) {
+    let path = golden_path(name);
+    if std::env::var_os("UPDATE_GOLDENS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, png).unwrap();
+        return;
+    }
+
+    let expected = Pixmap::load_png(&path)
+        .unwrap_or_else(|err| panic!("missing golden {}: {err}", path.display()));
+    let actual = Pixmap::decode_png(png).unwrap();
+
+    let differing = if expected.width() != actual.width() || expected.height() != actual.height()
+    {
+        usize::MAX
+    } else {
+        expected
+            .data()
+            .chunks(4)
+            .zip(actual.data().chunks(4))
+            .filter(|(expected, actual)| {
+                expected
+                    .iter()
+                    .zip(actual.iter())
+                    .any(|(e, a)| e.abs_diff(*a) > TOLERANCE)
+            })
+            .count()
+    };
+
+    if differing != 0 {
+        let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("{name}.actual.png"));
+        std::fs::write(&out, png).unwrap();
+        panic!(
+            "{name} differs from its golden in {differing} pixels; actual render written to {}",
+            out.display()
+        );
+    }
+}
+
+#[test]
+fn house_default() {
+    let png = render_png(IconName::House, 24, &IconConfig::default());
+    assert_golden("house-24", &png);
+}
+
+#[test]
+fn heart_filled() {
+    let config = IconConfig::new().stroke("#e11d48").fill("#fecdd3");
+    let png = render_png(IconName::Heart, 48, &config);
+    assert_golden("heart-48-filled", &png);
+}
+
+#[test]
+fn star_thin_stroke() {
+    let config = IconConfig::new().stroke("#f59e0b").stroke_width("1.5");
+    let png = render_png(IconName::Star, 64, &config);
+    assert_golden("star-64-thin", &png);
+}
+
+#[test]
+fn search_large() {
+    let config = IconConfig::new().stroke("#2563eb");
+    let png = render_png(IconName::Search, 128, &config);
+    assert_golden("search-128", &png);
+}
+
+#[test]
+fn user_ignores_config_size() {
+    let config = IconConfig::new().size("512");
+    let png = render_png(IconName::User, 32, &config);
+    assert_eq!(Pixmap::decode_png(&png).unwrap().width(), 32);
+    assert_golden("user-32", &png);
+}
+
+#[test]
+fn sizes_are_clamped() {
+    // Width and height are the first fields of the IHDR chunk
+    let dimensions = |png: &[u8]| {
+        let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+        let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+        (width, height)
+    };
+
+    let png = render_png(IconName::House, u32::MAX, &IconConfig::default());
+    assert_eq!(dimensions(&png), (MAX_PNG_SIZE, MAX_PNG_SIZE));
+
+    let png = render_png(IconName::House, 0, &IconConfig::default());
+    assert_eq!(dimensions(&png), (1, 1));
+}
@@ -0,0 +1,270 @@
+//! Server-rendered icons compared with the string renderer and with Lucide.
+//!
+//! The components and `render_icon_svg` render the same node data embedded at
+//! build time, so every icon's server output is compared with the string
+//! renderer's. Since a bug in that shared data would pass that comparison, a
+//! few icons covering each kind of shape are also checked against fixed markup
+//! copied from Lucide's source SVGs.
+//!
+//! Run with `cargo test --features ssr`.
+#![cfg(feature = "ssr")]
+
+mod common;
+
+use common::ssr;
+use leptos::prelude::*;
+use leptos_lucide_rs::*;
+
+type Component = fn(LucideProps) -> AnyView;
+
+#[test]
+fn every_icon_matches_expected_markup() {
+    let mismatches: Vec<&str> = IconName::ALL
+        .iter()
+        .filter(|icon| {
+            let html = ssr(|| load_icon(icon.as_str()).to_html());
+            html != render_icon_svg(**icon, &IconConfig::default())
+        })
+        .map(|icon| icon.as_str())
+        .collect();
+
+    assert!(mismatches.is_empty(), "mismatched icons: {mismatches:?}");
+}
+
+/// `<svg>` root every icon renders with the default config
+fn lucide_root(name: &str) -> String {
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" data-lucide="{name}" class="lucide-icon">"#
+    )
+}
+
+#[test]
+fn shapes_match_lucide_source() {
+    // Children as they appear in Lucide's own SVG for each icon
+    let cases = [
+        ("minus", r#"<path d="M5 12h14"></path>"#),
+        ("circle", r#"<circle cx="12" cy="12" r="10"></circle>"#),
+        (
+            "square",
+            r#"<rect width="18" height="18" x="3" y="3" rx="2"></rect>"#,
+        ),
+        (
+            "equal",
+            concat!(
+                r#"<line x1="5" x2="19" y1="9" y2="9"></line>"#,
+                r#"<line x1="5" x2="19" y1="15" y2="15"></line>"#,
+            ),
+        ),
+        (
+            "inbox",
+            concat!(
+                r#"<polyline points="22 12 16 12 14 15 10 15 8 12 2 12"></polyline>"#,
+                r#"<path d="M5.45 5.11 2 12v6a2 2 0 0 0 2 2h16a2 2 0 0 0 2-2v-6l-3.45-6.89A2 2 0 0 0 16.76 4H7.24a2 2 0 0 0-1.79 1.11z"></path>"#,
+            ),
+        ),
+        (
+            "sword",
+            concat!(
+                r#"<polyline points="14.5 17.5 3 6 3 3 6 3 17.5 14.5"></polyline>"#,
+                r#"<line x1="13" x2="19" y1="19" y2="13"></line>"#,
+                r#"<line x1="16" x2="20" y1="16" y2="20"></line>"#,
+                r#"<line x1="19" x2="21" y1="21" y2="19"></line>"#,
+            ),
+        ),
+    ];
+
+    for (name, children) in cases {
+        let expected = format!("{}{children}</svg>", lucide_root(name));
+        assert_eq!(ssr(|| load_icon(name).to_html()), expected, "{name}");
+    }
+}
+
+/// `html` without one pair of branch markers, `<!--bo-…-->` to `<!--bc-…-->`
+fn strip_branch(html: &str) -> Option<String> {
+    let (before, rest) = html.split_once("<!--bo-")?;
+    let (_, rest) = rest.split_once("-->")?;
+    let (inner, rest) = rest.rsplit_once("<!--bc-")?;
+    let (_, after) = rest.split_once("-->")?;
+    Some(format!("{before}{inner}{after}"))
+}
+
+#[test]
+fn icons_have_no_hydration_markers_inside() {
+    // `AnyView` brackets itself with one pair of branch markers. Icons whose
+    // filled shapes are drawn first when filled also choose their children's
+    // order from their props, which the client does the same way. Anything
+    // else would mean the client has dynamic parts to reconcile
+    for icon in IconName::ALL {
+        let plain = ssr(|| load_icon(icon.as_str()).to_html());
+        let branching = ssr(|| load_icon(icon.as_str()).to_html_branching());
+        let inner = strip_branch(&branching).unwrap();
+        let children = strip_branch(&inner);
+
+        assert!(!plain.contains("<!--"), "{icon}");
+        match children {
+            Some(children) => {
+                assert_eq!(children, plain, "{icon}");
+                assert!(inner.contains("><!--bo-"), "{icon}: {inner}");
+                assert!(inner.ends_with("--></svg>"), "{icon}: {inner}");
+            }
+            None => assert_eq!(inner, plain, "{icon}"),
+        }
+    }
+}
+
+#[test]
+fn configured_components_match_expected_markup() {
+    let config = IconConfig::new()
+        .class("text-red-500")
+        .style("vertical-align: middle;")
+        .size("32")
+        .stroke("#e11d48")
+        .stroke_width("1.5");
+
+    let cases: [(IconName, Component); 3] = [
+        (IconName::House, |props| House(props).into_any()),
+        (IconName::CircleAlert, |props| CircleAlert(props).into_any()),
+        (IconName::Star, |props| Star(props).into_any()),
+    ];
+
+    for (icon, component) in cases {
+        let html = ssr(|| component(config.clone().into()).to_html());
+        assert_eq!(html, render_icon_svg(icon, &config), "{icon}");
+    }
+}
+
+#[test]
+fn animations_add_their_class() {
+    for &animation in IconAnimation::ALL {
+        let config = IconConfig::new().class("busy").animation(animation);
+        let html = ssr(|| LoaderCircle(config.clone().into()).to_html());
+        assert_eq!(html, render_icon_svg(IconName::LoaderCircle, &config));
+        let class = format!(r#"class="lucide-icon {} busy""#, animation.class());
+        assert!(html.contains(&class), "{html}");
+    }
+}
+
+#[test]
+fn filled_components_match_expected_markup() {
+    let configs = [
+        IconConfig::new().filled(true),
+        IconConfig::new()
+            .filled(true)
+            .fill_color("#fde68a")
+            .stroke("#f59e0b"),
+        IconConfig::new().fill_color("#fde68a"),
+    ];
+    let cases: [(IconName, Component); 5] = [
+        (IconName::Heart, |props| Heart(props).into_any()),
+        (IconName::Star, |props| Star(props).into_any()),
+        (IconName::Mail, |props| Mail(props).into_any()),
+        (IconName::CircleAlert, |props| CircleAlert(props).into_any()),
+        (IconName::ArrowRight, |props| ArrowRight(props).into_any()),
+    ];
+
+    for config in configs {
+        for (icon, component) in cases {
+            let html = ssr(|| component(config.clone().into()).to_html());
+            assert_eq!(html, render_icon_svg(icon, &config), "{icon}");
+        }
+    }
+}
+
+/// Child elements of a rendered `<svg>` without a `fill` of their own
+fn unfilled_children(svg: &str) -> usize {
+    svg.split('<')
+        .skip(2)
+        .filter(|tag| !tag.starts_with('/') && !tag.contains("fill="))
+        .count()
+}
+
+#[test]
+fn filled_paints_only_closed_shapes() {
+    let filled = IconConfig::new().filled(true);
+
+    // Every shape of a heart or star is closed; an arrow has none
+    assert_eq!(
+        unfilled_children(&render_icon_svg(IconName::Heart, &filled)),
+        0
+    );
+    assert_eq!(
+        unfilled_children(&render_icon_svg(IconName::Star, &filled)),
+        0
+    );
+    assert_eq!(
+        unfilled_children(&render_icon_svg(IconName::ArrowRight, &filled)),
+        2
+    );
+    // The envelope is filled but its flap stays a stroke, drawn on top
+    let mail = render_icon_svg(IconName::Mail, &filled);
+    assert_eq!(unfilled_children(&mail), 1);
+    assert!(
+        mail.contains(r#"fill="currentColor"></rect><path"#),
+        "{mail}"
+    );
+    // Unfilled, the flap comes first as in Lucide's source
+    let mail = render_icon_svg(IconName::Mail, &IconConfig::default());
+    assert!(mail.contains("></path><rect"), "{mail}");
+    let mail = ssr(|| Mail(LucideProps::default()).to_html());
+    assert!(mail.contains("></path><rect"), "{mail}");
+
+    // Fills follow the stroke unless `fill_color` is set
+    let heart = render_icon_svg(IconName::Heart, &filled.clone().stroke("red"));
+    assert!(heart.contains(r#"fill="red"></path>"#), "{heart}");
+    let heart = render_icon_svg(IconName::Heart, &filled.stroke("red").fill_color("pink"));
+    assert!(heart.contains(r#"fill="pink"></path>"#), "{heart}");
+    // The root stays unfilled so open strokes never get a fill
+    assert!(heart.contains(r#"fill="none""#), "{heart}");
+}
+
+#[test]
+fn contrasting_fills_keep_inner_strokes_visible() {
+    // The check inside the circle is an open stroke, drawn over the fill in
+    // the stroke color; with the default fill both are the same color
+    let filled = IconConfig::new().filled(true).stroke("#16a34a");
+    let check = render_icon_svg(IconName::CircleCheck, &filled);
+    assert!(
+        check.contains(r##"fill="#16a34a"></circle><path d="m9 12 2 2 4-4"></path>"##),
+        "{check}"
+    );
+
+    let check = render_icon_svg(IconName::CircleCheck, &filled.fill_color("#dcfce7"));
+    assert!(
+        check.contains(r##"fill="#dcfce7"></circle><path d="m9 12 2 2 4-4"></path>"##),
+        "{check}"
+    );
+    assert!(check.contains(r##"stroke="#16a34a""##), "{check}");
+}
+
+#[test]
+fn icon_macro_applies_fill() {
+    let html = ssr(|| icon!(Heart, filled(true), fill_color("crimson")).to_html());
+    assert!(html.contains(r#"fill="crimson"></path>"#), "{html}");
+
+    let html = ssr(|| icon!(Square, fill("gold")).to_html());
+    assert!(html.contains(r#"fill="gold""#), "{html}");
+
+    let html = ssr(|| icon!(Star, wrapper = "button", filled(true)).to_html());
+    assert!(html.starts_with(r#"<button type="button""#), "{html}");
+    assert!(html.contains(r#"fill="currentColor"></path>"#), "{html}");
+}
+
+#[test]
+fn aliases_render_their_replacement() {
+    for (old, new) in [
+        ("home", IconName::House),
+        ("alert-circle", IconName::CircleAlert),
+        ("edit", IconName::Pencil),
+    ] {
+        let html = ssr(|| load_icon(old).to_html());
+        assert_eq!(html, render_icon_svg(new, &IconConfig::default()), "{old}");
+    }
+}
+
+#[test]
+fn unknown_names_render_placeholder() {
+    let html = ssr(|| load_icon("not-a-lucide-icon").to_html());
+    let expected =
+        SvgAttributes::new(&IconConfig::default()).render("not-a-lucide-icon", PLACEHOLDER_NODES);
+    assert_eq!(html, expected);
+}
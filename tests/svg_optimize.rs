@@ -0,0 +1,261 @@
+//! The build-time SVG optimizer must not change how any icon renders.
+//!
+//! Every icon in the archive is optimized and compared with its source: the
+//! same elements, the same path commands and every number within rounding
+//! distance. With the `raster` feature, each embedded icon is also rendered
+//! next to its source and compared pixel by pixel.
+
+#[path = "../build/svg.rs"]
+#[allow(dead_code)]
+mod svg;
+
+use leptos_lucide_rs::*;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use svg::{SvgDocument, SvgNode};
+
+/// `(name, source)` of every icon in the archive
+fn sources() -> &'static [(String, String)] {
+    static SOURCES: OnceLock<Vec<(String, String)>> = OnceLock::new();
+    SOURCES.get_or_init(|| {
+        let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("lucide-icons");
+        lucide_svg_rs::LucideClient::new(lucide_svg_rs::ICONS_TAR)
+            .and_then(|client| client.download_all_icons(&dir))
+            .expect("icon archive extracts");
+
+        let mut sources: Vec<(String, String)> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "svg"))
+            .map(|path| {
+                let name = path.file_stem().unwrap().to_string_lossy().to_string();
+                (name, std::fs::read_to_string(&path).unwrap())
+            })
+            .collect();
+        sources.sort();
+        sources
+    })
+}
+
+enum Token {
+    Command(char),
+    Number(f64),
+}
+
+/// Split path data or an attribute value into commands and numbers
+fn tokenize(value: &str) -> Vec<Token> {
+    let bytes = value.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_alphabetic() {
+            tokens.push(Token::Command(c as char));
+            i += 1;
+        } else if c.is_ascii_digit() || matches!(c, b'-' | b'+' | b'.') {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if bytes[start] != b'.' && i < bytes.len() && bytes[i] == b'.' {
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            tokens.push(Token::Number(value[start..i].parse().unwrap()));
+        } else {
+            i += 1;
+        }
+    }
+    tokens
+}
+
+fn equivalent(source: &str, optimized: &str, precision: usize) -> bool {
+    let tolerance = 0.5 * 10f64.powi(-(precision as i32)) + 1e-9;
+    let (source, optimized) = (tokenize(source), tokenize(optimized));
+    source.len() == optimized.len()
+        && source.iter().zip(&optimized).all(|pair| match pair {
+            (Token::Command(a), Token::Command(b)) => a == b,
+            (Token::Number(a), Token::Number(b)) => (a - b).abs() <= tolerance,
+            _ => false,
+        })
+}
+
+/// Assert `optimized` draws the same shapes as `document`'s children
+fn assert_same_geometry(
+    name: &str,
+    document: &SvgDocument,
+    optimized: &[SvgNode],
+    precision: usize,
+) {
+    assert_eq!(
+        document.children.len(),
+        optimized.len(),
+        "{name}: element count"
+    );
+
+    for (source, optimized) in document.children.iter().zip(optimized) {
+        assert_eq!(source.tag, optimized.tag, "{name}: element order");
+        for (attr, value) in &source.attrs {
+            match optimized.attrs.iter().find(|(other, _)| other == attr) {
+                Some((_, new_value)) => assert!(
+                    equivalent(value, new_value, precision),
+                    "{name}: {attr}=\"{value}\" became \"{new_value}\""
+                ),
+                None => assert!(
+                    document
+                        .root_attrs
+                        .iter()
+                        .any(|(root_attr, root_value)| root_attr == attr
+                            && root_value == value.trim()),
+                    "{name}: dropped {attr}=\"{value}\" the root doesn't set"
+                ),
+            }
+        }
+        assert!(
+            source.attrs.len() >= optimized.attrs.len(),
+            "{name}: added attributes"
+        );
+    }
+}
+
+#[test]
+fn rounds_numbers() {
+    assert_eq!(svg::round_numbers("M1.23456 -0.5001", 3), "M1.235 -.5");
+    assert_eq!(svg::round_numbers("1.0004.5", 3), "1 .5");
+    assert_eq!(svg::round_numbers("a1 1 0 011 1", 3), "a1 1 0 011 1");
+    assert_eq!(svg::round_numbers("-0.0001", 3), "0");
+    assert_eq!(svg::round_numbers(".76-.001", 2), ".76 0");
+    assert_eq!(svg::round_numbers(".88.001", 2), ".88 0");
+    assert_eq!(svg::round_numbers("1.5e-3", 1), "1.5e-3");
+    assert_eq!(svg::round_numbers("2.5", 0), "2");
+}
+
+#[test]
+fn compacts_path_data() {
+    assert_eq!(svg::compact_path("M 12 2 L 2 7 l 10 -5"), "M12 2L2 7l10-5");
+    assert_eq!(svg::compact_path("M1,2 , 3"), "M1,2,3");
+    assert_eq!(svg::collapse_whitespace("  M1 \n\t 2  "), "M1 2");
+}
+
+#[test]
+fn detects_closed_paths() {
+    // Lucide's heart ends where it started instead of with `z`
+    assert!(svg::path_is_closed(
+        "M2 9.5a5.5 5.5 0 0 1 9.591-3.676.56.56 0 0 0 .818 0A5.49 5.49 0 0 1 22 9.5\
+         c0 2.29-1.5 4-3 5.5l-5.492 5.313a2 2 0 0 1-3 .019L5 15c-1.5-1.5-3-3.2-3-5.5"
+    ));
+    assert!(svg::path_is_closed("M3 3h4v4H3zm7 7h4v4h-4z"));
+    assert!(svg::path_is_closed("M12 2a10 10 0 1 0 0 20a10 10 0 1 0 0-20"));
+    assert!(svg::path_is_closed("M1 1a1 1 0 011 1 1 1 0 01-1-1"));
+    assert!(!svg::path_is_closed("M5 12h14"));
+    assert!(!svg::path_is_closed("m12 5 7 7-7 7"));
+    assert!(!svg::path_is_closed("M12 2L2 7l10 5 10-5-10-5zM2 17l10 5 10-5"));
+    assert!(!svg::path_is_closed("M1 1"));
+    assert!(!svg::path_is_closed("1 1"));
+}
+
+#[test]
+fn drops_only_inherited_attributes() {
+    let document = svg::parse_svg(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none" stroke-width="2">
+            <circle cx="12.00049" cy="12" r="10" fill="none" stroke-width="1.5"/>
+        </svg>"#,
+    )
+    .unwrap();
+
+    let optimized = svg::optimize(&document, 3);
+    assert_eq!(
+        optimized[0].attrs,
+        [
+            ("cx".to_string(), "12".to_string()),
+            ("cy".to_string(), "12".to_string()),
+            ("r".to_string(), "10".to_string()),
+            ("stroke-width".to_string(), "1.5".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn every_icon_keeps_its_geometry() {
+    for precision in [svg::DEFAULT_PRECISION, 2] {
+        for (name, source) in sources() {
+            let document = svg::parse_svg(source).unwrap();
+            let optimized = svg::optimize(&document, precision);
+            assert_same_geometry(name, &document, &optimized, precision);
+        }
+    }
+}
+
+#[test]
+fn embedded_icons_are_optimized() {
+    if std::env::var_os("LEPTOS_LUCIDE_PRECISION").is_some() {
+        return;
+    }
+
+    for (name, source) in sources() {
+        let optimized = svg::optimize(&svg::parse_svg(source).unwrap(), svg::DEFAULT_PRECISION);
+        let embedded = IconName::from_name(name).unwrap().nodes();
+        assert_eq!(optimized.len(), embedded.len(), "{name}");
+        for (optimized, embedded) in optimized.iter().zip(embedded) {
+            assert_eq!(optimized.tag, embedded.tag, "{name}");
+            let attrs: Vec<(&str, &str)> = optimized
+                .attrs
+                .iter()
+                .map(|(attr, value)| (attr.as_str(), value.as_str()))
+                .collect();
+            assert_eq!(attrs, embedded.attrs, "{name}");
+            assert_eq!(svg::is_closed(optimized), embedded.closed, "{name}");
+        }
+    }
+}
+
+#[test]
+fn optimizing_keeps_shapes_closed() {
+    for (name, source) in sources() {
+        let document = svg::parse_svg(source).unwrap();
+        let optimized = svg::optimize(&document, svg::DEFAULT_PRECISION);
+        for (source, optimized) in document.children.iter().zip(&optimized) {
+            assert_eq!(svg::is_closed(source), svg::is_closed(optimized), "{name}");
+        }
+    }
+}
+
+#[cfg(feature = "raster")]
+#[test]
+fn embedded_icons_render_like_their_source() {
+    use resvg::{tiny_skia, usvg};
+
+    const SIZE: u32 = 48;
+    const TOLERANCE: u8 = 8;
+
+    let render = |svg: &str| {
+        let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+        let scale = SIZE as f32 / tree.size().width();
+        let mut pixmap = tiny_skia::Pixmap::new(SIZE, SIZE).unwrap();
+        resvg::render(
+            &tree,
+            tiny_skia::Transform::from_scale(scale, scale),
+            &mut pixmap.as_mut(),
+        );
+        pixmap
+    };
+
+    for (name, source) in sources() {
+        let icon = IconName::from_name(name).unwrap();
+        let expected = render(source);
+        let actual = render(&render_icon_svg(icon, &IconConfig::default()));
+        let differing = expected
+            .data()
+            .iter()
+            .zip(actual.data())
+            .filter(|(e, a)| e.abs_diff(**a) > TOLERANCE)
+            .count();
+        assert_eq!(
+            differing, 0,
+            "{name} renders differently after optimization"
+        );
+    }
+}